@@ -30,7 +30,15 @@ export default function GameCreationFormModal({
   const { conn } = useSpacetime();
 
   const handleCreateGame = async ({ values }: { values: FormValues }) => {
-    conn?.reducers.createGame(values.boardSize, 0);
+    conn?.reducers.createGame(
+      values.boardSize,
+      0,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      undefined
+    );
   };
   const form = useForm({
     defaultValues: {
@@ -1,9 +1,11 @@
 export type Occupant = "Empty" | "Black" | "White";
+export type GroupStatus = "Alive" | "Dead";
 export type SpotState = {
   occupant: Occupant;
   move_number: number | null;
   marker: string | null;
   scoring_owner: Occupant | null;
   scoring_explanation: string | null;
+  group_status?: GroupStatus | null;
   playable: boolean;
 };
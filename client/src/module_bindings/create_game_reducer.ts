@@ -34,6 +34,11 @@ import {
 export type CreateGame = {
   boardSize: number | undefined,
   handicap: number | undefined,
+  freeHandicap: boolean | undefined,
+  ruleset: string | undefined,
+  komi: number | undefined,
+  timeControl: string | undefined,
+  botLevel: string | undefined,
 };
 
 /**
@@ -48,6 +53,11 @@ export namespace CreateGame {
     return AlgebraicType.createProductType([
       new ProductTypeElement("boardSize", AlgebraicType.createOptionType(AlgebraicType.createU8Type())),
       new ProductTypeElement("handicap", AlgebraicType.createOptionType(AlgebraicType.createU8Type())),
+      new ProductTypeElement("freeHandicap", AlgebraicType.createOptionType(AlgebraicType.createBoolType())),
+      new ProductTypeElement("ruleset", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("komi", AlgebraicType.createOptionType(AlgebraicType.createF32Type())),
+      new ProductTypeElement("timeControl", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("botLevel", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
     ]);
   }
 
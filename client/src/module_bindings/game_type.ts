@@ -38,10 +38,30 @@ export type Game = {
   turn: string,
   passes: number,
   boardSize: number,
-  previousBoard: string | undefined,
+  positionHistory: bigint[],
+  ruleset: string,
+  handicap: number,
+  handicapPending: number,
+  komi: number,
+  capturesBlack: number,
+  capturesWhite: number,
+  moveCount: number,
   gameOver: boolean,
+  scoringPhase: boolean,
+  deadStones: string[],
+  acceptedBlack: boolean,
+  acceptedWhite: boolean,
   finalScoreBlack: number | undefined,
   finalScoreWhite: number | undefined,
+  winner: string | undefined,
+  resultReason: string | undefined,
+  resultMargin: number | undefined,
+  result: string | undefined,
+  timeControl: string | undefined,
+  clockBlack: string | undefined,
+  clockWhite: string | undefined,
+  turnStartedAt: Timestamp | undefined,
+  botLevel: string | undefined,
 };
 
 /**
@@ -61,10 +81,30 @@ export namespace Game {
       new ProductTypeElement("turn", AlgebraicType.createStringType()),
       new ProductTypeElement("passes", AlgebraicType.createU8Type()),
       new ProductTypeElement("boardSize", AlgebraicType.createU8Type()),
-      new ProductTypeElement("previousBoard", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("positionHistory", AlgebraicType.createArrayType(AlgebraicType.createU64Type())),
+      new ProductTypeElement("ruleset", AlgebraicType.createStringType()),
+      new ProductTypeElement("handicap", AlgebraicType.createU8Type()),
+      new ProductTypeElement("handicapPending", AlgebraicType.createU8Type()),
+      new ProductTypeElement("komi", AlgebraicType.createF32Type()),
+      new ProductTypeElement("capturesBlack", AlgebraicType.createU32Type()),
+      new ProductTypeElement("capturesWhite", AlgebraicType.createU32Type()),
+      new ProductTypeElement("moveCount", AlgebraicType.createU32Type()),
       new ProductTypeElement("gameOver", AlgebraicType.createBoolType()),
+      new ProductTypeElement("scoringPhase", AlgebraicType.createBoolType()),
+      new ProductTypeElement("deadStones", AlgebraicType.createArrayType(AlgebraicType.createStringType())),
+      new ProductTypeElement("acceptedBlack", AlgebraicType.createBoolType()),
+      new ProductTypeElement("acceptedWhite", AlgebraicType.createBoolType()),
       new ProductTypeElement("finalScoreBlack", AlgebraicType.createOptionType(AlgebraicType.createF32Type())),
       new ProductTypeElement("finalScoreWhite", AlgebraicType.createOptionType(AlgebraicType.createF32Type())),
+      new ProductTypeElement("winner", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("resultReason", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("resultMargin", AlgebraicType.createOptionType(AlgebraicType.createF32Type())),
+      new ProductTypeElement("result", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("timeControl", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("clockBlack", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("clockWhite", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
+      new ProductTypeElement("turnStartedAt", AlgebraicType.createOptionType(AlgebraicType.createTimestampType())),
+      new ProductTypeElement("botLevel", AlgebraicType.createOptionType(AlgebraicType.createStringType())),
     ]);
   }
 
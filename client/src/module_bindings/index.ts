@@ -32,36 +32,78 @@ import {
 } from "@clockworklabs/spacetimedb-sdk";
 
 // Import and reexport all reducer arg types
+import { AcceptScore } from "./accept_score_reducer.ts";
+export { AcceptScore };
+import { CheckClock } from "./check_clock_reducer.ts";
+export { CheckClock };
 import { ClientConnected } from "./client_connected_reducer.ts";
 export { ClientConnected };
 import { ClientDisconnected } from "./client_disconnected_reducer.ts";
 export { ClientDisconnected };
 import { CreateGame } from "./create_game_reducer.ts";
 export { CreateGame };
+import { ExportSgf } from "./export_sgf_reducer.ts";
+export { ExportSgf };
+import { ImportSgf } from "./import_sgf_reducer.ts";
+export { ImportSgf };
 import { JoinGame } from "./join_game_reducer.ts";
 export { JoinGame };
 import { PassMove } from "./pass_move_reducer.ts";
 export { PassMove };
+import { PlaceHandicapStone } from "./place_handicap_stone_reducer.ts";
+export { PlaceHandicapStone };
 import { PlaceStone } from "./place_stone_reducer.ts";
 export { PlaceStone };
+import { PlayBotTurn } from "./play_bot_turn_reducer.ts";
+export { PlayBotTurn };
+import { Resign } from "./resign_reducer.ts";
+export { Resign };
+import { ResumePlay } from "./resume_play_reducer.ts";
+export { ResumePlay };
 import { Seed } from "./seed_reducer.ts";
 export { Seed };
 import { SendMessage } from "./send_message_reducer.ts";
 export { SendMessage };
 import { SetName } from "./set_name_reducer.ts";
 export { SetName };
+import { ToggleDeadStones } from "./toggle_dead_stones_reducer.ts";
+export { ToggleDeadStones };
 
 // Import and reexport all table handle types
 import { GameTableHandle } from "./game_table.ts";
 export { GameTableHandle };
+import { GameCommentTableHandle } from "./game_comment_table.ts";
+export { GameCommentTableHandle };
+import { GameMarkupTableHandle } from "./game_markup_table.ts";
+export { GameMarkupTableHandle };
+import { GameMoveTableHandle } from "./game_move_table.ts";
+export { GameMoveTableHandle };
+import { GameSgfTableHandle } from "./game_sgf_table.ts";
+export { GameSgfTableHandle };
+import { GameVariationTableHandle } from "./game_variation_table.ts";
+export { GameVariationTableHandle };
 import { MessageTableHandle } from "./message_table.ts";
 export { MessageTableHandle };
 import { UserTableHandle } from "./user_table.ts";
 export { UserTableHandle };
 
 // Import and reexport all types
+import { BotTurn } from "./bot_turn_type.ts";
+export { BotTurn };
+import { ClockCheck } from "./clock_check_type.ts";
+export { ClockCheck };
 import { Game } from "./game_type.ts";
 export { Game };
+import { GameComment } from "./game_comment_type.ts";
+export { GameComment };
+import { GameMarkup } from "./game_markup_type.ts";
+export { GameMarkup };
+import { GameMove } from "./game_move_type.ts";
+export { GameMove };
+import { GameSgf } from "./game_sgf_type.ts";
+export { GameSgf };
+import { GameVariation } from "./game_variation_type.ts";
+export { GameVariation };
 import { Message } from "./message_type.ts";
 export { Message };
 import { User } from "./user_type.ts";
@@ -74,6 +116,31 @@ const REMOTE_MODULE = {
       rowType: Game.getTypeScriptAlgebraicType(),
       primaryKey: "id",
     },
+    game_comment: {
+      tableName: "game_comment",
+      rowType: GameComment.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    game_markup: {
+      tableName: "game_markup",
+      rowType: GameMarkup.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    game_move: {
+      tableName: "game_move",
+      rowType: GameMove.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    game_sgf: {
+      tableName: "game_sgf",
+      rowType: GameSgf.getTypeScriptAlgebraicType(),
+      primaryKey: "gameId",
+    },
+    game_variation: {
+      tableName: "game_variation",
+      rowType: GameVariation.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
     message: {
       tableName: "message",
       rowType: Message.getTypeScriptAlgebraicType(),
@@ -85,6 +152,14 @@ const REMOTE_MODULE = {
     },
   },
   reducers: {
+    accept_score: {
+      reducerName: "accept_score",
+      argsType: AcceptScore.getTypeScriptAlgebraicType(),
+    },
+    check_clock: {
+      reducerName: "check_clock",
+      argsType: CheckClock.getTypeScriptAlgebraicType(),
+    },
     client_connected: {
       reducerName: "client_connected",
       argsType: ClientConnected.getTypeScriptAlgebraicType(),
@@ -97,6 +172,14 @@ const REMOTE_MODULE = {
       reducerName: "create_game",
       argsType: CreateGame.getTypeScriptAlgebraicType(),
     },
+    export_sgf: {
+      reducerName: "export_sgf",
+      argsType: ExportSgf.getTypeScriptAlgebraicType(),
+    },
+    import_sgf: {
+      reducerName: "import_sgf",
+      argsType: ImportSgf.getTypeScriptAlgebraicType(),
+    },
     join_game: {
       reducerName: "join_game",
       argsType: JoinGame.getTypeScriptAlgebraicType(),
@@ -105,10 +188,26 @@ const REMOTE_MODULE = {
       reducerName: "pass_move",
       argsType: PassMove.getTypeScriptAlgebraicType(),
     },
+    place_handicap_stone: {
+      reducerName: "place_handicap_stone",
+      argsType: PlaceHandicapStone.getTypeScriptAlgebraicType(),
+    },
     place_stone: {
       reducerName: "place_stone",
       argsType: PlaceStone.getTypeScriptAlgebraicType(),
     },
+    play_bot_turn: {
+      reducerName: "play_bot_turn",
+      argsType: PlayBotTurn.getTypeScriptAlgebraicType(),
+    },
+    resign: {
+      reducerName: "resign",
+      argsType: Resign.getTypeScriptAlgebraicType(),
+    },
+    resume_play: {
+      reducerName: "resume_play",
+      argsType: ResumePlay.getTypeScriptAlgebraicType(),
+    },
     seed: {
       reducerName: "seed",
       argsType: Seed.getTypeScriptAlgebraicType(),
@@ -121,6 +220,10 @@ const REMOTE_MODULE = {
       reducerName: "set_name",
       argsType: SetName.getTypeScriptAlgebraicType(),
     },
+    toggle_dead_stones: {
+      reducerName: "toggle_dead_stones",
+      argsType: ToggleDeadStones.getTypeScriptAlgebraicType(),
+    },
   },
   // Constructors which are used by the DbConnectionImpl to
   // extract type information from the generated RemoteModule.
@@ -148,20 +251,61 @@ const REMOTE_MODULE = {
 
 // A type representing all the possible variants of a reducer.
 export type Reducer = never
+| { name: "AcceptScore", args: AcceptScore }
+| { name: "CheckClock", args: CheckClock }
 | { name: "ClientConnected", args: ClientConnected }
 | { name: "ClientDisconnected", args: ClientDisconnected }
 | { name: "CreateGame", args: CreateGame }
+| { name: "ExportSgf", args: ExportSgf }
+| { name: "ImportSgf", args: ImportSgf }
 | { name: "JoinGame", args: JoinGame }
 | { name: "PassMove", args: PassMove }
+| { name: "PlaceHandicapStone", args: PlaceHandicapStone }
 | { name: "PlaceStone", args: PlaceStone }
+| { name: "PlayBotTurn", args: PlayBotTurn }
+| { name: "Resign", args: Resign }
+| { name: "ResumePlay", args: ResumePlay }
 | { name: "Seed", args: Seed }
 | { name: "SendMessage", args: SendMessage }
 | { name: "SetName", args: SetName }
+| { name: "ToggleDeadStones", args: ToggleDeadStones }
 ;
 
 export class RemoteReducers {
   constructor(private connection: DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
 
+  acceptScore(gameId: bigint) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    AcceptScore.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("accept_score", __argsBuffer, this.setCallReducerFlags.acceptScoreFlags);
+  }
+
+  onAcceptScore(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.onReducer("accept_score", callback);
+  }
+
+  removeOnAcceptScore(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.offReducer("accept_score", callback);
+  }
+
+  checkClock(check: ClockCheck) {
+    const __args = { check };
+    let __writer = new BinaryWriter(1024);
+    CheckClock.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("check_clock", __argsBuffer, this.setCallReducerFlags.checkClockFlags);
+  }
+
+  onCheckClock(callback: (ctx: ReducerEventContext, check: ClockCheck) => void) {
+    this.connection.onReducer("check_clock", callback);
+  }
+
+  removeOnCheckClock(callback: (ctx: ReducerEventContext, check: ClockCheck) => void) {
+    this.connection.offReducer("check_clock", callback);
+  }
+
   clientConnected() {
     this.connection.callReducer("client_connected", new Uint8Array(0), this.setCallReducerFlags.clientConnectedFlags);
   }
@@ -186,22 +330,54 @@ export class RemoteReducers {
     this.connection.offReducer("client_disconnected", callback);
   }
 
-  createGame(boardSize: number | undefined, handicap: number | undefined) {
-    const __args = { boardSize, handicap };
+  createGame(boardSize: number | undefined, handicap: number | undefined, freeHandicap: boolean | undefined, ruleset: string | undefined, komi: number | undefined, timeControl: string | undefined, botLevel: string | undefined) {
+    const __args = { boardSize, handicap, freeHandicap, ruleset, komi, timeControl, botLevel };
     let __writer = new BinaryWriter(1024);
     CreateGame.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("create_game", __argsBuffer, this.setCallReducerFlags.createGameFlags);
   }
 
-  onCreateGame(callback: (ctx: ReducerEventContext, boardSize: number | undefined, handicap: number | undefined) => void) {
+  onCreateGame(callback: (ctx: ReducerEventContext, boardSize: number | undefined, handicap: number | undefined, freeHandicap: boolean | undefined, ruleset: string | undefined, komi: number | undefined, timeControl: string | undefined, botLevel: string | undefined) => void) {
     this.connection.onReducer("create_game", callback);
   }
 
-  removeOnCreateGame(callback: (ctx: ReducerEventContext, boardSize: number | undefined, handicap: number | undefined) => void) {
+  removeOnCreateGame(callback: (ctx: ReducerEventContext, boardSize: number | undefined, handicap: number | undefined, freeHandicap: boolean | undefined, ruleset: string | undefined, komi: number | undefined, timeControl: string | undefined, botLevel: string | undefined) => void) {
     this.connection.offReducer("create_game", callback);
   }
 
+  exportSgf(gameId: bigint) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    ExportSgf.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("export_sgf", __argsBuffer, this.setCallReducerFlags.exportSgfFlags);
+  }
+
+  onExportSgf(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.onReducer("export_sgf", callback);
+  }
+
+  removeOnExportSgf(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.offReducer("export_sgf", callback);
+  }
+
+  importSgf(sgf: string) {
+    const __args = { sgf };
+    let __writer = new BinaryWriter(1024);
+    ImportSgf.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("import_sgf", __argsBuffer, this.setCallReducerFlags.importSgfFlags);
+  }
+
+  onImportSgf(callback: (ctx: ReducerEventContext, sgf: string) => void) {
+    this.connection.onReducer("import_sgf", callback);
+  }
+
+  removeOnImportSgf(callback: (ctx: ReducerEventContext, sgf: string) => void) {
+    this.connection.offReducer("import_sgf", callback);
+  }
+
   joinGame(gameId: bigint) {
     const __args = { gameId };
     let __writer = new BinaryWriter(1024);
@@ -234,6 +410,22 @@ export class RemoteReducers {
     this.connection.offReducer("pass_move", callback);
   }
 
+  placeHandicapStone(gameId: bigint, x: number, y: number) {
+    const __args = { gameId, x, y };
+    let __writer = new BinaryWriter(1024);
+    PlaceHandicapStone.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("place_handicap_stone", __argsBuffer, this.setCallReducerFlags.placeHandicapStoneFlags);
+  }
+
+  onPlaceHandicapStone(callback: (ctx: ReducerEventContext, gameId: bigint, x: number, y: number) => void) {
+    this.connection.onReducer("place_handicap_stone", callback);
+  }
+
+  removeOnPlaceHandicapStone(callback: (ctx: ReducerEventContext, gameId: bigint, x: number, y: number) => void) {
+    this.connection.offReducer("place_handicap_stone", callback);
+  }
+
   placeStone(gameId: bigint, x: number, y: number) {
     const __args = { gameId, x, y };
     let __writer = new BinaryWriter(1024);
@@ -250,6 +442,54 @@ export class RemoteReducers {
     this.connection.offReducer("place_stone", callback);
   }
 
+  playBotTurn(turn: BotTurn) {
+    const __args = { turn };
+    let __writer = new BinaryWriter(1024);
+    PlayBotTurn.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("play_bot_turn", __argsBuffer, this.setCallReducerFlags.playBotTurnFlags);
+  }
+
+  onPlayBotTurn(callback: (ctx: ReducerEventContext, turn: BotTurn) => void) {
+    this.connection.onReducer("play_bot_turn", callback);
+  }
+
+  removeOnPlayBotTurn(callback: (ctx: ReducerEventContext, turn: BotTurn) => void) {
+    this.connection.offReducer("play_bot_turn", callback);
+  }
+
+  resign(gameId: bigint) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    Resign.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("resign", __argsBuffer, this.setCallReducerFlags.resignFlags);
+  }
+
+  onResign(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.onReducer("resign", callback);
+  }
+
+  removeOnResign(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.offReducer("resign", callback);
+  }
+
+  resumePlay(gameId: bigint) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    ResumePlay.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("resume_play", __argsBuffer, this.setCallReducerFlags.resumePlayFlags);
+  }
+
+  onResumePlay(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.onReducer("resume_play", callback);
+  }
+
+  removeOnResumePlay(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.offReducer("resume_play", callback);
+  }
+
   seed() {
     this.connection.callReducer("seed", new Uint8Array(0), this.setCallReducerFlags.seedFlags);
   }
@@ -294,9 +534,35 @@ export class RemoteReducers {
     this.connection.offReducer("set_name", callback);
   }
 
+  toggleDeadStones(gameId: bigint, x: number, y: number) {
+    const __args = { gameId, x, y };
+    let __writer = new BinaryWriter(1024);
+    ToggleDeadStones.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("toggle_dead_stones", __argsBuffer, this.setCallReducerFlags.toggleDeadStonesFlags);
+  }
+
+  onToggleDeadStones(callback: (ctx: ReducerEventContext, gameId: bigint, x: number, y: number) => void) {
+    this.connection.onReducer("toggle_dead_stones", callback);
+  }
+
+  removeOnToggleDeadStones(callback: (ctx: ReducerEventContext, gameId: bigint, x: number, y: number) => void) {
+    this.connection.offReducer("toggle_dead_stones", callback);
+  }
+
 }
 
 export class SetReducerFlags {
+  acceptScoreFlags: CallReducerFlags = 'FullUpdate';
+  acceptScore(flags: CallReducerFlags) {
+    this.acceptScoreFlags = flags;
+  }
+
+  checkClockFlags: CallReducerFlags = 'FullUpdate';
+  checkClock(flags: CallReducerFlags) {
+    this.checkClockFlags = flags;
+  }
+
   clientConnectedFlags: CallReducerFlags = 'FullUpdate';
   clientConnected(flags: CallReducerFlags) {
     this.clientConnectedFlags = flags;
@@ -312,6 +578,16 @@ export class SetReducerFlags {
     this.createGameFlags = flags;
   }
 
+  exportSgfFlags: CallReducerFlags = 'FullUpdate';
+  exportSgf(flags: CallReducerFlags) {
+    this.exportSgfFlags = flags;
+  }
+
+  importSgfFlags: CallReducerFlags = 'FullUpdate';
+  importSgf(flags: CallReducerFlags) {
+    this.importSgfFlags = flags;
+  }
+
   joinGameFlags: CallReducerFlags = 'FullUpdate';
   joinGame(flags: CallReducerFlags) {
     this.joinGameFlags = flags;
@@ -322,11 +598,31 @@ export class SetReducerFlags {
     this.passMoveFlags = flags;
   }
 
+  placeHandicapStoneFlags: CallReducerFlags = 'FullUpdate';
+  placeHandicapStone(flags: CallReducerFlags) {
+    this.placeHandicapStoneFlags = flags;
+  }
+
   placeStoneFlags: CallReducerFlags = 'FullUpdate';
   placeStone(flags: CallReducerFlags) {
     this.placeStoneFlags = flags;
   }
 
+  playBotTurnFlags: CallReducerFlags = 'FullUpdate';
+  playBotTurn(flags: CallReducerFlags) {
+    this.playBotTurnFlags = flags;
+  }
+
+  resignFlags: CallReducerFlags = 'FullUpdate';
+  resign(flags: CallReducerFlags) {
+    this.resignFlags = flags;
+  }
+
+  resumePlayFlags: CallReducerFlags = 'FullUpdate';
+  resumePlay(flags: CallReducerFlags) {
+    this.resumePlayFlags = flags;
+  }
+
   seedFlags: CallReducerFlags = 'FullUpdate';
   seed(flags: CallReducerFlags) {
     this.seedFlags = flags;
@@ -342,6 +638,11 @@ export class SetReducerFlags {
     this.setNameFlags = flags;
   }
 
+  toggleDeadStonesFlags: CallReducerFlags = 'FullUpdate';
+  toggleDeadStones(flags: CallReducerFlags) {
+    this.toggleDeadStonesFlags = flags;
+  }
+
 }
 
 export class RemoteTables {
@@ -351,6 +652,26 @@ export class RemoteTables {
     return new GameTableHandle(this.connection.clientCache.getOrCreateTable<Game>(REMOTE_MODULE.tables.game));
   }
 
+  get gameComment(): GameCommentTableHandle {
+    return new GameCommentTableHandle(this.connection.clientCache.getOrCreateTable<GameComment>(REMOTE_MODULE.tables.game_comment));
+  }
+
+  get gameMarkup(): GameMarkupTableHandle {
+    return new GameMarkupTableHandle(this.connection.clientCache.getOrCreateTable<GameMarkup>(REMOTE_MODULE.tables.game_markup));
+  }
+
+  get gameMove(): GameMoveTableHandle {
+    return new GameMoveTableHandle(this.connection.clientCache.getOrCreateTable<GameMove>(REMOTE_MODULE.tables.game_move));
+  }
+
+  get gameSgf(): GameSgfTableHandle {
+    return new GameSgfTableHandle(this.connection.clientCache.getOrCreateTable<GameSgf>(REMOTE_MODULE.tables.game_sgf));
+  }
+
+  get gameVariation(): GameVariationTableHandle {
+    return new GameVariationTableHandle(this.connection.clientCache.getOrCreateTable<GameVariation>(REMOTE_MODULE.tables.game_variation));
+  }
+
   get message(): MessageTableHandle {
     return new MessageTableHandle(this.connection.clientCache.getOrCreateTable<Message>(REMOTE_MODULE.tables.message));
   }
@@ -69,7 +69,7 @@ We support two main scoring systems:
 - **Calculation:**  
   **Score = (Number of empty intersections completely enclosed by a player's stones) + (Captured stones, if tracked)**
   
-  *Note:* Our implementation focuses on the enclosed empty regions. Only regions that do not touch the board edge and are bordered by a single color are counted.
+  *Note:* An empty region is territory for a player if only that player's stones border it, once dead stones have been removed. The board edge encloses territory just like stones do, so regions along the edge count. Regions bordered by both colors are neutral (dame).
 
 #### Komi
 - **Komi:**  
@@ -0,0 +1,34 @@
+/*!
+ * Runs the rules engine as a Go Text Protocol (GTP) engine on standard input
+ * and output.
+ *
+ * Usage: `gtp [ruleset]`, where the optional ruleset is an identifier such as
+ * `japanese` or `tromp_taylor` (Chinese rules by default).
+ */
+
+use go_engine::gtp::{run_gtp, GtpEngine};
+use go_engine::models::Ruleset;
+use std::io;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let ruleset = match std::env::args().nth(1) {
+        Some(name) => match name.parse::<Ruleset>() {
+            Ok(ruleset) => ruleset,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Ruleset::default(),
+    };
+
+    let mut engine = GtpEngine::new(ruleset);
+    match run_gtp(&mut engine, io::stdin().lock(), io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
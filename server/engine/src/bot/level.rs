@@ -0,0 +1,110 @@
+use super::mcts::MctsConfig;
+use std::fmt;
+use std::str::FromStr;
+
+/// Moves each playout may take on average before the search stops early: a little
+/// more than a playout from an empty 9×9 board lasts, so only larger boards are cut
+/// short.
+const MOVES_PER_PLAYOUT: u64 = 120;
+
+/// How strongly the built-in bot plays.
+///
+/// Each level sets the playout budget of the bot's Monte Carlo tree search and how
+/// often it strays from the move the search likes best. The budgets are sized for
+/// 9×9 and 13×13 games: the weakest levels make the loose moves a beginner can
+/// punish, while the strongest reads far enough to give club players a game. On
+/// larger boards, where playouts last longer, the search also stops once it has
+/// played as many moves as its playouts would take on 9×9, so a move takes about
+/// as long on any board.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BotLevel {
+    /// Very few playouts, choosing freely among the moves tried
+    Beginner,
+    /// A small search that often plays a second-best move
+    #[default]
+    Easy,
+    /// A medium search that rarely strays from its best move
+    Medium,
+    /// The largest search, always playing its best move
+    Hard,
+}
+
+impl BotLevel {
+    /// All levels, from weakest to strongest.
+    pub const ALL: [BotLevel; 4] = [
+        BotLevel::Beginner,
+        BotLevel::Easy,
+        BotLevel::Medium,
+        BotLevel::Hard,
+    ];
+
+    /// Returns the identifier used to store this level on a game.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BotLevel::Beginner => "beginner",
+            BotLevel::Easy => "easy",
+            BotLevel::Medium => "medium",
+            BotLevel::Hard => "hard",
+        }
+    }
+
+    /// Returns the number of playouts the bot runs for each move at this level.
+    pub fn playouts(&self) -> u32 {
+        match self {
+            BotLevel::Beginner => 100,
+            BotLevel::Easy => 400,
+            BotLevel::Medium => 1200,
+            BotLevel::Hard => 3000,
+        }
+    }
+
+    /// Returns the most moves the bot's playouts may take in all for each move at
+    /// this level (see [`MctsConfig::max_moves`]).
+    pub fn max_moves(&self) -> u64 {
+        self.playouts() as u64 * MOVES_PER_PLAYOUT
+    }
+
+    /// Returns the temperature of the bot's final choice at this level (see
+    /// [`MctsConfig::temperature`]).
+    pub fn temperature(&self) -> f32 {
+        match self {
+            BotLevel::Beginner => 1.0,
+            BotLevel::Easy => 0.5,
+            BotLevel::Medium => 0.2,
+            BotLevel::Hard => 0.0,
+        }
+    }
+
+    /// Returns the search settings for a move at this level.
+    ///
+    /// # Arguments
+    /// * `seed` - Seed for the search's random number generator
+    pub fn mcts_config(&self, seed: u64) -> MctsConfig {
+        MctsConfig {
+            playouts: self.playouts(),
+            temperature: self.temperature(),
+            max_moves: self.max_moves(),
+            seed,
+        }
+    }
+}
+
+impl fmt::Display for BotLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BotLevel {
+    type Err = String;
+
+    /// Parses a level identifier such as `"medium"`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_ascii_lowercase();
+        BotLevel::ALL
+            .iter()
+            .copied()
+            .find(|level| level.as_str() == normalized)
+            .ok_or_else(|| format!("Unknown bot level: {}", s))
+    }
+}
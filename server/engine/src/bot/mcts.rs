@@ -0,0 +1,529 @@
+use super::player::{score_lead, BotInput, BotMove};
+use crate::models::{position_key, Bitboard, Chains, Occupant, Point, Position, Ruleset};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Weight of the exploration term in the UCT formula.
+const EXPLORATION: f32 = 0.2;
+
+/// Bias of the RAVE estimate, in β = ñ / (n + ñ + RAVE_BIAS·n·ñ) for n playouts
+/// through a move and ñ playouts that played it later. The smaller it is, the longer
+/// a move's AMAF value is trusted over its own results.
+const RAVE_BIAS: f32 = 0.001;
+
+/// Value of a move no playout has tried yet, above any real win rate so every
+/// move gets tried.
+const FIRST_PLAY_URGENCY: f32 = 1.1;
+
+/// Number of playouts through a node before its children are added to the tree.
+const EXPANSION_THRESHOLD: u32 = 8;
+
+/// Playouts end after this many moves per point on the board, which stops the
+/// superko cycles their short-sighted ko check can't see.
+const PLAYOUT_MOVES_PER_POINT: usize = 3;
+
+/// Win rate of the best move below which the bot resigns.
+const RESIGN_WIN_RATE: f32 = 0.05;
+
+/// Settings of a Monte Carlo tree search.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MctsConfig {
+    /// Number of playouts run for each move
+    pub playouts: u32,
+
+    /// How far the final choice strays from the most visited move: 0 always plays
+    /// it, 1 picks among the moves in proportion to their visits
+    pub temperature: f32,
+
+    /// Most moves to play in all playouts together; the search stops early once it
+    /// has played this many, which bounds its running time on large boards
+    pub max_moves: u64,
+
+    /// Seed for the search's random number generator
+    pub seed: u64,
+}
+
+/// A move in the search tree, with the results of the playouts through it.
+#[derive(Clone, Debug)]
+struct Node {
+    /// Index of the point played, or None for a pass
+    point: Option<usize>,
+
+    /// The player who made the move
+    color: Occupant,
+
+    /// Indices of the nodes for the replies, once expanded
+    children: Vec<usize>,
+
+    /// Whether the replies have been added to the tree
+    expanded: bool,
+
+    /// Playouts through this move
+    visits: u32,
+
+    /// Playouts through this move won by `color`, with jigo counting half
+    wins: f32,
+
+    /// Playouts from the parent in which `color` played this point later on
+    amaf_visits: u32,
+
+    /// Of those, the playouts won by `color`
+    amaf_wins: f32,
+}
+
+impl Node {
+    fn new(point: Option<usize>, color: Occupant) -> Self {
+        Node {
+            point,
+            color,
+            children: Vec::new(),
+            expanded: false,
+            visits: 0,
+            wins: 0.0,
+            amaf_visits: 0,
+            amaf_wins: 0.0,
+        }
+    }
+
+    /// Returns the fraction of playouts through this move won by its player.
+    fn win_rate(&self) -> f32 {
+        if self.visits == 0 {
+            0.0
+        } else {
+            self.wins / self.visits as f32
+        }
+    }
+
+    /// Returns the value used to select this move, blending its own win rate with
+    /// its AMAF win rate (RAVE) and adding the UCT exploration term.
+    fn value(&self, parent_visits: u32) -> f32 {
+        if self.visits == 0 && self.amaf_visits == 0 {
+            return FIRST_PLAY_URGENCY;
+        }
+        let n = self.visits as f32;
+        let amaf_n = self.amaf_visits as f32;
+        let beta = amaf_n / (n + amaf_n + RAVE_BIAS * n * amaf_n);
+        let amaf_rate = if self.amaf_visits == 0 { 0.0 } else { self.amaf_wins / amaf_n };
+        let exploration = ((parent_visits.max(1) as f32).ln() / (n + 1.0)).sqrt();
+        (1.0 - beta) * self.win_rate() + beta * amaf_rate + EXPLORATION * exploration
+    }
+}
+
+/// Marks points that are not in the list of empty points.
+const NOT_EMPTY: usize = usize::MAX;
+
+/// The game as it stands during one playout.
+///
+/// A search keeps one state for the root and one scratch state that is reset from
+/// it with `clone_from` before every playout, reusing its buffers.
+struct SearchState {
+    /// The stones on the board
+    chains: Chains,
+
+    /// Position keys of every position so far, ending with the current one
+    history: Vec<u64>,
+
+    /// The player to move
+    to_move: Occupant,
+
+    /// Consecutive passes just played
+    passes: u8,
+
+    /// The last stone played, if the last move wasn't a pass
+    last_point: Option<usize>,
+
+    /// Every stone played since the root of the tree, with its color
+    moves: Vec<(usize, Occupant)>,
+
+    /// The empty points, in no particular order
+    empty: Vec<usize>,
+
+    /// Where each empty point is in `empty`, or `NOT_EMPTY` for stones
+    empty_slots: Vec<usize>,
+}
+
+impl Clone for SearchState {
+    fn clone(&self) -> Self {
+        SearchState {
+            chains: self.chains.clone(),
+            history: self.history.clone(),
+            to_move: self.to_move.clone(),
+            passes: self.passes,
+            last_point: self.last_point,
+            moves: self.moves.clone(),
+            empty: self.empty.clone(),
+            empty_slots: self.empty_slots.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.chains.clone_from(&source.chains);
+        self.history.clone_from(&source.history);
+        self.to_move = source.to_move.clone();
+        self.passes = source.passes;
+        self.last_point = source.last_point;
+        self.moves.clone_from(&source.moves);
+        self.empty.clone_from(&source.empty);
+        self.empty_slots.clone_from(&source.empty_slots);
+    }
+}
+
+impl SearchState {
+    /// Creates the state at the root of the search.
+    fn new(input: &BotInput) -> Self {
+        let chains = Chains::from_board(input.board);
+        let mut empty_slots = vec![NOT_EMPTY; (input.board.board_size as usize).pow(2)];
+        let empty: Vec<usize> = chains.position().empty().iter().collect();
+        for (slot, &idx) in empty.iter().enumerate() {
+            empty_slots[idx] = slot;
+        }
+        SearchState {
+            chains,
+            history: input.position_history.to_vec(),
+            to_move: input.to_move.clone(),
+            passes: if input.opponent_passed { 1 } else { 0 },
+            last_point: None,
+            moves: Vec::new(),
+            empty,
+            empty_slots,
+        }
+    }
+
+    /// Plays a stone at `point`, or passes if it is None, without checking legality.
+    fn play(&mut self, point: Option<usize>) {
+        match point {
+            Some(idx) => {
+                let effect = self.chains.play(idx, self.to_move.clone());
+                self.remove_empty(idx);
+                for &stone in effect.captured.iter().chain(&effect.suicided) {
+                    self.empty_slots[stone] = self.empty.len();
+                    self.empty.push(stone);
+                }
+                self.moves.push((idx, self.to_move.clone()));
+                self.passes = 0;
+            }
+            None => self.passes += 1,
+        }
+        self.last_point = point;
+        self.to_move = opponent(&self.to_move);
+        self.history
+            .push(position_key(self.chains.position_hash(), &self.to_move));
+    }
+
+    /// Takes a point that has just been played out of the list of empty points.
+    fn remove_empty(&mut self, idx: usize) {
+        let slot = self.empty_slots[idx];
+        self.empty.swap_remove(slot);
+        if let Some(&moved) = self.empty.get(slot) {
+            self.empty_slots[moved] = slot;
+        }
+        self.empty_slots[idx] = NOT_EMPTY;
+    }
+
+    /// Swaps two entries of the list of empty points.
+    fn swap_empty(&mut self, a: usize, b: usize) {
+        self.empty.swap(a, b);
+        self.empty_slots[self.empty[a]] = a;
+        self.empty_slots[self.empty[b]] = b;
+    }
+
+    /// Returns whether the player to move may play at `idx` without filling one of
+    /// their own eyes, checking ko only against the given recent positions.
+    fn is_playable(&self, idx: usize, ruleset: Ruleset, history: &[u64]) -> bool {
+        !is_own_eye(self.chains.position(), idx, &self.to_move)
+            && self
+                .chains
+                .check_move(idx, &self.to_move, ruleset, history)
+                .is_ok()
+    }
+
+    /// Chooses a move for a playout, or None to pass.
+    ///
+    /// The playout captures the last stone played if it is left in atari, and
+    /// otherwise plays a random legal move that doesn't fill its own eye. Only the
+    /// last two positions are checked for repetitions, which is enough to stop
+    /// simple ko fights.
+    ///
+    /// Candidates are drawn from the list of empty points, moving each rejected one
+    /// to the end of the list so it isn't drawn again.
+    fn playout_move(&mut self, ruleset: Ruleset, rng: &mut StdRng) -> Option<usize> {
+        let recent = self.history.len().saturating_sub(2);
+
+        if let Some(last) = self.last_point {
+            if self.chains.liberty_count(last) == Some(1) {
+                let liberty = self.chains.chain_liberties(last).and_then(|l| l.first());
+                if let Some(idx) =
+                    liberty.filter(|&idx| self.is_playable(idx, ruleset, &self.history[recent..]))
+                {
+                    return Some(idx);
+                }
+            }
+        }
+
+        let mut remaining = self.empty.len();
+        while remaining > 0 {
+            let slot = rng.gen_range(0..remaining);
+            let idx = self.empty[slot];
+            if self.is_playable(idx, ruleset, &self.history[recent..]) {
+                return Some(idx);
+            }
+            remaining -= 1;
+            self.swap_empty(slot, remaining);
+        }
+        None
+    }
+}
+
+/// Returns the other player.
+fn opponent(color: &Occupant) -> Occupant {
+    if *color == Occupant::Black {
+        Occupant::White
+    } else {
+        Occupant::Black
+    }
+}
+
+/// Returns whether the empty point at `idx` is an eye of `color`: every neighbor is
+/// one of its stones, and no more than one diagonal (none on the edge) is held by
+/// the opponent, so the eye can't be made false.
+fn is_own_eye(position: &Position, idx: usize, color: &Occupant) -> bool {
+    let neighbors = position.geometry().adjacent(Bitboard::single(idx));
+    if (neighbors & position.stones(color)) != neighbors {
+        return false;
+    }
+
+    let size = position.board_size() as isize;
+    let (row, col) = (idx as isize / size, idx as isize % size);
+    let opponent_stones = position.stones(&opponent(color));
+    let mut off_board = 0;
+    let mut opponent_diagonals = 0;
+    for (dr, dc) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+        let (r, c) = (row + dr, col + dc);
+        if r < 0 || c < 0 || r >= size || c >= size {
+            off_board += 1;
+        } else if opponent_stones.contains((r * size + c) as usize) {
+            opponent_diagonals += 1;
+        }
+    }
+    if off_board > 0 {
+        opponent_diagonals == 0
+    } else {
+        opponent_diagonals <= 1
+    }
+}
+
+/// Counts a finished playout by area, with every empty point bordered by only one
+/// color counted for that color.
+///
+/// # Returns
+/// 1.0 if Black wins, 0.0 if White wins and 0.5 for a jigo
+fn black_result(position: &Position, komi: f32) -> f32 {
+    let black = position.stones(&Occupant::Black);
+    let white = position.stones(&Occupant::White);
+    let mut score = black.count() as f32 - white.count() as f32 - komi;
+    for idx in position.empty().iter() {
+        let neighbors = position.geometry().adjacent(Bitboard::single(idx));
+        let touches_black = !(neighbors & black).is_empty();
+        let touches_white = !(neighbors & white).is_empty();
+        if touches_black && !touches_white {
+            score += 1.0;
+        } else if touches_white && !touches_black {
+            score -= 1.0;
+        }
+    }
+    if score > 0.0 {
+        1.0
+    } else if score < 0.0 {
+        0.0
+    } else {
+        0.5
+    }
+}
+
+/// Adds the replies to a node: every legal move that doesn't fill the mover's own
+/// eye, in random order, or a pass if there is no such move.
+///
+/// Passing while there are moves left is never searched: a pass has no AMAF
+/// statistics, so with few playouts per move its win rate is mostly noise.
+fn expand(tree: &mut Vec<Node>, node: usize, state: &SearchState, ruleset: Ruleset, rng: &mut StdRng) {
+    let mut empty = state.empty.clone();
+    empty.sort_unstable();
+    let mut points: Vec<Option<usize>> = empty
+        .into_iter()
+        .filter(|&idx| state.is_playable(idx, ruleset, &state.history))
+        .map(Some)
+        .collect();
+    points.shuffle(rng);
+    if points.is_empty() {
+        points.push(None);
+    }
+
+    for point in points {
+        let child = tree.len();
+        tree.push(Node::new(point, state.to_move.clone()));
+        tree[node].children.push(child);
+    }
+    tree[node].expanded = true;
+}
+
+/// Returns the child of a node with the highest selection value.
+fn select(tree: &[Node], node: usize) -> usize {
+    let parent_visits = tree[node].visits;
+    let mut best = tree[node].children[0];
+    let mut best_value = f32::MIN;
+    for &child in &tree[node].children {
+        let value = tree[child].value(parent_visits);
+        if value > best_value {
+            best = child;
+            best_value = value;
+        }
+    }
+    best
+}
+
+/// Chooses the bot's move with a Monte Carlo tree search.
+///
+/// Each playout descends the tree by UCT, with move values blended with their
+/// all-moves-as-first (RAVE) statistics, then plays the game out with random moves
+/// that never fill the player's own eyes and counts it by area. The move with the
+/// most playouts is chosen, or with a temperature above 0, a move picked at random
+/// in proportion to its playouts. The search stops early once its playouts have
+/// played `config.max_moves` moves. The bot passes after its opponent passes if it is
+/// not behind, or when it has no move that doesn't fill its own eyes, and resigns
+/// once even its best move wins almost no playouts late in the game.
+///
+/// # Arguments
+/// * `input` - The game as the bot sees it
+/// * `config` - The playout and move budgets, temperature and seed of the search
+///
+/// # Returns
+/// The same move every time for the same input and configuration
+pub fn choose_mcts_move(input: &BotInput, config: &MctsConfig) -> BotMove {
+    if input.opponent_passed && score_lead(input) >= 0.0 {
+        return BotMove::Pass;
+    }
+
+    let size = input.board.board_size;
+    let points = (size as usize).pow(2);
+    let max_playout_moves = points * PLAYOUT_MOVES_PER_POINT;
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let root = SearchState::new(input);
+    let mut tree = vec![Node::new(None, opponent(input.to_move))];
+
+    // Scratch buffers reused by every playout
+    let mut state = root.clone();
+    let mut path = Vec::new();
+    let mut move_offsets = Vec::new();
+    let mut first_player: Vec<Option<Occupant>> = vec![None; points];
+    let mut moves_played: u64 = 0;
+
+    for playout in 0..config.playouts.max(1) {
+        if playout > 0 && moves_played >= config.max_moves {
+            break;
+        }
+        state.clone_from(&root);
+        path.clear();
+        path.push(0);
+        move_offsets.clear();
+        move_offsets.push(0);
+
+        // Descend the tree, expanding the first node that has had enough playouts
+        let mut node = 0;
+        while state.passes < 2 {
+            if !tree[node].expanded {
+                if node != 0 && tree[node].visits < EXPANSION_THRESHOLD {
+                    break;
+                }
+                expand(&mut tree, node, &state, input.ruleset, &mut rng);
+            }
+            node = select(&tree, node);
+            state.play(tree[node].point);
+            path.push(node);
+            move_offsets.push(state.moves.len());
+        }
+
+        // Play the game out from there
+        for _ in 0..max_playout_moves {
+            if state.passes >= 2 {
+                break;
+            }
+            let point = state.playout_move(input.ruleset, &mut rng);
+            state.play(point);
+        }
+        moves_played += (state.history.len() - root.history.len()) as u64;
+        let result = black_result(state.chains.position(), input.komi);
+        let reward = |color: &Occupant| {
+            if *color == Occupant::Black {
+                result
+            } else {
+                1.0 - result
+            }
+        };
+
+        // Update the moves on the path, and the AMAF statistics of their siblings
+        // for every point the same player went on to play first
+        first_player.fill(None);
+        let mut next_move = state.moves.len();
+        for (depth, &node) in path.iter().enumerate().rev() {
+            while next_move > move_offsets[depth] {
+                next_move -= 1;
+                let (idx, color) = &state.moves[next_move];
+                first_player[*idx] = Some(color.clone());
+            }
+
+            tree[node].visits += 1;
+            tree[node].wins += reward(&tree[node].color);
+            for i in 0..tree[node].children.len() {
+                let child_id = tree[node].children[i];
+                let child = &mut tree[child_id];
+                let played_later = child
+                    .point
+                    .is_some_and(|idx| first_player[idx].as_ref() == Some(&child.color));
+                if played_later {
+                    child.amaf_visits += 1;
+                    child.amaf_wins += reward(&child.color);
+                }
+            }
+        }
+    }
+
+    // The most visited move, the first of equals in the shuffled order
+    let root = &tree[0];
+    let mut best = root.children[0];
+    for &child in &root.children {
+        if tree[child].visits > tree[best].visits {
+            best = child;
+        }
+    }
+    if tree[best].win_rate() < RESIGN_WIN_RATE && input.move_count as usize > points / 4 {
+        return BotMove::Resign;
+    }
+
+    let mut chosen = best;
+    if config.temperature > 0.0 && tree[best].point.is_some() {
+        let weights: Vec<(usize, f64)> = root
+            .children
+            .iter()
+            .filter(|&&child| tree[child].point.is_some() && tree[child].visits > 0)
+            .map(|&child| {
+                let weight = (tree[child].visits as f64).powf(1.0 / config.temperature as f64);
+                (child, weight)
+            })
+            .collect();
+        let total: f64 = weights.iter().map(|(_, weight)| weight).sum();
+        let mut target = rng.gen::<f64>() * total;
+        for (child, weight) in weights {
+            chosen = child;
+            target -= weight;
+            if target < 0.0 {
+                break;
+            }
+        }
+    }
+
+    match tree[chosen].point {
+        Some(idx) => Point::from_index(idx, size).map_or(BotMove::Pass, BotMove::Play),
+        None => BotMove::Pass,
+    }
+}
@@ -0,0 +1,17 @@
+/*!
+ * A computer opponent.
+ *
+ * This module chooses moves for the built-in bot and for the GTP engine's
+ * `genmove`, deciding when to pass and when the game is hopeless enough to
+ * resign. The GTP engine uses simple tactics, while the built-in bot runs a
+ * Monte Carlo tree search whose strength is set by a difficulty level.
+ * Randomness comes from the caller, so a seeded generator gives repeatable games.
+ */
+
+pub mod level;
+pub mod mcts;
+pub mod player;
+
+pub use level::*;
+pub use mcts::*;
+pub use player::*;
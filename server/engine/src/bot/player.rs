@@ -0,0 +1,188 @@
+use crate::models::{Board, Chains, Occupant, Point, Ruleset, ScoringMethod};
+use crate::scoring::{calculate_score, find_dead_stones, remove_marked_stones};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Fraction of the board's points the bot must be behind by before it resigns.
+const RESIGN_MARGIN: f32 = 0.25;
+
+/// What the bot decided to do on its turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BotMove {
+    /// Play a stone on the point
+    Play(Point),
+    /// Pass the turn
+    Pass,
+    /// Resign the game
+    Resign,
+}
+
+/// The state of a game, as the bot sees it on its turn.
+#[derive(Clone, Copy, Debug)]
+pub struct BotInput<'a> {
+    /// The current board
+    pub board: &'a Board,
+
+    /// The bot's color
+    pub to_move: &'a Occupant,
+
+    /// The ruleset the game is played under
+    pub ruleset: Ruleset,
+
+    /// Position keys of every position so far, ending with the current one
+    pub position_history: &'a [u64],
+
+    /// Points added to White's score, including handicap compensation
+    pub komi: f32,
+
+    /// Prisoners taken as (by Black, by White)
+    pub captures: (u32, u32),
+
+    /// Number of moves played so far
+    pub move_count: u32,
+
+    /// Whether the opponent passed on their last turn
+    pub opponent_passed: bool,
+}
+
+/// Estimates the score of a position as it would be counted now.
+///
+/// Stones proven dead by Benson's algorithm are removed and counted as prisoners
+/// first, except under Tromp-Taylor rules, which count every stone on the board.
+///
+/// # Arguments
+/// * `board` - The board to count
+/// * `ruleset` - The ruleset deciding how the board is counted
+/// * `komi` - Points added to White's score, including handicap compensation
+/// * `captures` - Prisoners taken as (by Black, by White)
+///
+/// # Returns
+/// A tuple (black_score, white_score)
+pub fn estimate_score(board: &Board, ruleset: Ruleset, komi: f32, captures: (u32, u32)) -> (f32, f32) {
+    let mut board = board.clone();
+    let method = ruleset.scoring_method();
+    let dead = if method == ScoringMethod::TrompTaylor {
+        Vec::new()
+    } else {
+        find_dead_stones(&board)
+    };
+    let (black_prisoners, white_prisoners) = remove_marked_stones(&mut board, &dead);
+    let captures = (captures.0 + black_prisoners, captures.1 + white_prisoners);
+    calculate_score(&board, method, komi, captures)
+}
+
+/// Returns whether the bot agrees with the stones marked dead in the scoring phase.
+///
+/// The bot accepts exactly the stones [`find_dead_stones`] proves dead, the same ones
+/// its [`estimate_score`] takes off the board, so its opponent cannot end the game by
+/// marking its living groups dead.
+///
+/// # Arguments
+/// * `board` - The board being scored
+/// * `dead_stones` - The stones marked dead
+pub fn accepts_dead_stones(board: &Board, dead_stones: &[Point]) -> bool {
+    let mut marked = dead_stones.to_vec();
+    marked.sort();
+    marked.dedup();
+    let mut dead = find_dead_stones(board);
+    dead.sort();
+    marked == dead
+}
+
+/// Returns how far the player to move is ahead by the [`estimate_score`] of the
+/// position, or a negative number if they are behind.
+pub(crate) fn score_lead(input: &BotInput) -> f32 {
+    let (black_score, white_score) =
+        estimate_score(input.board, input.ruleset, input.komi, input.captures);
+    if *input.to_move == Occupant::Black {
+        black_score - white_score
+    } else {
+        white_score - black_score
+    }
+}
+
+/// Returns whether playing at `point` would fill one of `color`'s own eyes, i.e.
+/// every neighbor of the point is already a stone of that color.
+pub fn fills_own_eye(board: &Board, point: Point, color: &Occupant) -> bool {
+    board
+        .neighbors(point)
+        .iter()
+        .all(|&n| board.get(n).is_some_and(|spot| spot.occupant == *color))
+}
+
+/// Chooses the bot's move with simple tactics.
+///
+/// The bot resigns once more than half the board's points have been played and it
+/// is behind by more than a quarter of them. It passes after its opponent passes if
+/// it is not behind, and whenever it has no move left that doesn't fill its own eyes.
+/// Otherwise it prefers captures (the more stones the better), then moves that
+/// give one of its chains in atari more liberties, then moves on the third and
+/// fourth lines, choosing at random among equally good moves.
+///
+/// # Arguments
+/// * `input` - The game as the bot sees it
+/// * `rng` - Source of randomness for breaking ties
+pub fn choose_bot_move<R: Rng + ?Sized>(input: &BotInput, rng: &mut R) -> BotMove {
+    let board = input.board;
+    let size = board.board_size;
+    let color = input.to_move;
+
+    let lead = score_lead(input);
+    let points = (size as u32 * size as u32) as f32;
+    if input.move_count as f32 > points / 2.0 && lead < -points * RESIGN_MARGIN {
+        return BotMove::Resign;
+    }
+    if input.opponent_passed && lead >= 0.0 {
+        return BotMove::Pass;
+    }
+
+    let chains = Chains::from_board(board);
+    let legal = chains.legal_moves(color, input.ruleset, input.position_history);
+    let mut candidates: Vec<Point> = legal
+        .iter()
+        .enumerate()
+        .filter(|(_, &legal)| legal)
+        .filter_map(|(idx, _)| Point::from_index(idx, size).ok())
+        .filter(|&point| !fills_own_eye(board, point, color))
+        .collect();
+    candidates.shuffle(rng);
+
+    let last_line = size - 1;
+    let move_value = |point: Point| {
+        let mut value = 0;
+        for n in board.neighbors(point) {
+            let idx = n.index(size);
+            if chains.liberty_count(idx) != Some(1) {
+                continue;
+            }
+            let stones = chains.chain_stones(idx).map_or(0, |stones| stones.count());
+            value += if board.spots[idx].occupant == *color {
+                10 * stones // Extend a chain in atari
+            } else {
+                20 * stones // Capture
+            };
+        }
+        let line = point
+            .row
+            .min(point.col)
+            .min(last_line - point.row)
+            .min(last_line - point.col);
+        if line == 2 || line == 3 {
+            value += 1;
+        }
+        value
+    };
+
+    // The first of the best moves, which the shuffle made a random one
+    let mut best: Option<(u32, Point)> = None;
+    for point in candidates {
+        let value = move_value(point);
+        if best.is_none_or(|(best_value, _)| value > best_value) {
+            best = Some((value, point));
+        }
+    }
+    match best {
+        Some((_, point)) => BotMove::Play(point),
+        None => BotMove::Pass,
+    }
+}
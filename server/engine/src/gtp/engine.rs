@@ -0,0 +1,476 @@
+use crate::bot::{choose_bot_move, estimate_score, BotInput, BotMove};
+use crate::models::{
+    position_key, Board, GameResult, Occupant, Point, Ruleset, ScoringMethod, SpotState,
+    MAX_BOARD_SIZE,
+};
+use crate::scoring::{find_dead_stones, find_groups, find_seki, remove_marked_stones};
+use crate::utils::{apply_move_to_board, fixed_handicap_points, place_setup_stone, position_hash};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::io::{self, BufRead, Write};
+
+/// Name reported by the `name` command.
+const NAME: &str = "GoTime";
+
+/// Every command the engine understands, in alphabetical order.
+const COMMANDS: [&str; 18] = [
+    "boardsize",
+    "clear_board",
+    "final_score",
+    "final_status_list",
+    "fixed_handicap",
+    "genmove",
+    "known_command",
+    "komi",
+    "list_commands",
+    "name",
+    "place_free_handicap",
+    "play",
+    "protocol_version",
+    "quit",
+    "set_free_handicap",
+    "showboard",
+    "undo",
+    "version",
+];
+
+/// The game state before a move, restored by `undo`.
+struct Snapshot {
+    board: Vec<SpotState>,
+    position_history: Vec<u64>,
+    captures: (u32, u32),
+    move_count: u64,
+    last_move_passed: bool,
+}
+
+/// A Go Text Protocol (version 2) engine playing on one board.
+///
+/// Moves are checked and applied with [`apply_move_to_board`] under the engine's
+/// ruleset, generated by the built-in bot (see [`choose_bot_move`]), and counted with
+/// [`estimate_score`], which removes the stones Benson's algorithm proves dead.
+pub struct GtpEngine {
+    ruleset: Ruleset,
+    board_size: u8,
+    komi: f32,
+    board: Vec<SpotState>,
+    position_history: Vec<u64>,
+    captures: (u32, u32),
+    move_count: u64,
+    last_move_passed: bool,
+    undo_stack: Vec<Snapshot>,
+    rng: StdRng,
+    quit: bool,
+}
+
+impl GtpEngine {
+    /// Creates an engine with an empty 19×19 board and the ruleset's default komi.
+    ///
+    /// Generated moves are random among equally good moves, but always the same for
+    /// the same sequence of commands.
+    ///
+    /// # Arguments
+    /// * `ruleset` - The ruleset deciding move legality and scoring
+    pub fn new(ruleset: Ruleset) -> Self {
+        let mut engine = GtpEngine {
+            ruleset,
+            board_size: 19,
+            komi: ruleset.default_komi(0, 19),
+            board: Vec::new(),
+            position_history: Vec::new(),
+            captures: (0, 0),
+            move_count: 0,
+            last_move_passed: false,
+            undo_stack: Vec::new(),
+            rng: StdRng::seed_from_u64(0),
+            quit: false,
+        };
+        engine.clear_board();
+        engine
+    }
+
+    /// Returns whether the controller has sent `quit`.
+    pub fn has_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Processes one line of input from the controller.
+    ///
+    /// Comments and control characters are removed first, and an optional numeric
+    /// command id is echoed in the response.
+    ///
+    /// # Returns
+    /// The response to send, ending with the blank line GTP requires, or None if the
+    /// line holds no command
+    pub fn respond(&mut self, line: &str) -> Option<String> {
+        let line = line.split('#').next().unwrap_or_default();
+        let line: String = line
+            .chars()
+            .filter(|c| !c.is_control() || *c == '\t')
+            .map(|c| if c == '\t' { ' ' } else { c })
+            .collect();
+        let mut words = line.split_whitespace().peekable();
+
+        let id = words
+            .next_if(|word| word.chars().all(|c| c.is_ascii_digit()))
+            .unwrap_or_default();
+        let command = words.next()?;
+        let args: Vec<&str> = words.collect();
+        let response = match self.execute(command, &args) {
+            Ok(result) => format!("={} {}\n\n", id, result),
+            Err(message) => format!("?{} {}\n\n", id, message),
+        };
+        Some(response)
+    }
+
+    /// Runs a single command.
+    ///
+    /// # Arguments
+    /// * `command` - The command name
+    /// * `args` - The command's arguments
+    ///
+    /// # Returns
+    /// * `Ok(result)` - The command's output, possibly empty
+    /// * `Err(message)` - The GTP error message, e.g. "illegal move"
+    pub fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "protocol_version" => Ok("2".to_string()),
+            "name" => Ok(NAME.to_string()),
+            "version" => Ok(env!("CARGO_PKG_VERSION").to_string()),
+            "known_command" => {
+                let name = args.first().ok_or("syntax error")?;
+                Ok(COMMANDS.contains(name).to_string())
+            }
+            "list_commands" => Ok(COMMANDS.join("\n")),
+            "quit" => {
+                self.quit = true;
+                Ok(String::new())
+            }
+            "boardsize" => {
+                let size: u8 = parse_arg(args, 0)?;
+                if !(2..=MAX_BOARD_SIZE).contains(&size) {
+                    return Err("unacceptable size".to_string());
+                }
+                self.board_size = size;
+                self.clear_board();
+                Ok(String::new())
+            }
+            "clear_board" => {
+                self.clear_board();
+                Ok(String::new())
+            }
+            "komi" => {
+                self.komi = parse_arg(args, 0)?;
+                Ok(String::new())
+            }
+            "play" => {
+                let color = parse_color(args.first())?;
+                let vertex = args.get(1).ok_or("syntax error")?;
+                let point = self.parse_vertex(vertex)?;
+                self.play(color, point).map_err(|_| "illegal move".to_string())?;
+                Ok(String::new())
+            }
+            "genmove" => {
+                let color = parse_color(args.first())?;
+                let board = Board::new(self.board.clone(), self.board_size);
+                let input = BotInput {
+                    board: &board,
+                    to_move: &color,
+                    ruleset: self.ruleset,
+                    position_history: &self.position_history,
+                    komi: self.komi,
+                    captures: self.captures,
+                    move_count: self.move_count as u32,
+                    opponent_passed: self.last_move_passed,
+                };
+                match choose_bot_move(&input, &mut self.rng) {
+                    BotMove::Play(point) => {
+                        self.play(color, Some(point))?;
+                        Ok(point.to_gtp(self.board_size))
+                    }
+                    BotMove::Pass => {
+                        self.play(color, None)?;
+                        Ok("pass".to_string())
+                    }
+                    BotMove::Resign => Ok("resign".to_string()),
+                }
+            }
+            "undo" => {
+                let snapshot = self.undo_stack.pop().ok_or("cannot undo")?;
+                self.board = snapshot.board;
+                self.position_history = snapshot.position_history;
+                self.captures = snapshot.captures;
+                self.move_count = snapshot.move_count;
+                self.last_move_passed = snapshot.last_move_passed;
+                Ok(String::new())
+            }
+            "fixed_handicap" => {
+                let stones: u8 = parse_arg(args, 0)?;
+                self.check_board_empty()?;
+                let points = fixed_handicap_points(self.board_size, stones)
+                    .map_err(|_| "invalid number of stones".to_string())?;
+                self.place_handicap(&points)
+            }
+            "place_free_handicap" => {
+                let stones: u8 = parse_arg(args, 0)?;
+                self.check_board_empty()?;
+                // The engine may place fewer stones than requested, so use as much
+                // of the fixed layout as the board has room for
+                let max_stones = (2..=9)
+                    .rev()
+                    .find(|&n| fixed_handicap_points(self.board_size, n).is_ok());
+                let points = match max_stones {
+                    Some(max_stones) if stones >= 2 => {
+                        fixed_handicap_points(self.board_size, stones.min(max_stones))?
+                    }
+                    _ => return Err("invalid number of stones".to_string()),
+                };
+                self.place_handicap(&points)
+            }
+            "set_free_handicap" => {
+                self.check_board_empty()?;
+                let mut points = Vec::new();
+                for vertex in args {
+                    match self.parse_vertex(vertex) {
+                        Ok(Some(point)) if !points.contains(&point) => points.push(point),
+                        _ => return Err("bad vertex list".to_string()),
+                    }
+                }
+                if points.len() < 2 {
+                    return Err("bad vertex list".to_string());
+                }
+                self.place_handicap(&points)
+                    .map(|_| String::new())
+                    .map_err(|_| "bad vertex list".to_string())
+            }
+            "final_score" => {
+                let board = Board::new(self.board.clone(), self.board_size);
+                let (black_score, white_score) =
+                    estimate_score(&board, self.ruleset, self.komi, self.captures);
+                Ok(GameResult::from_score(black_score, white_score).to_notation())
+            }
+            "final_status_list" => {
+                let status = args.first().ok_or("syntax error")?;
+                self.final_status_list(status)
+            }
+            "showboard" => Ok(self.showboard()),
+            _ => Err("unknown command".to_string()),
+        }
+    }
+
+    /// Empties the board and forgets the moves played on it.
+    fn clear_board(&mut self) {
+        let spot = SpotState {
+            occupant: Occupant::Empty,
+            move_number: None,
+            marker: None,
+            scoring_owner: None,
+            scoring_explanation: None,
+            group_status: None,
+            playable: true,
+        };
+        self.board = vec![spot; (self.board_size as usize).pow(2)];
+        self.position_history = vec![position_key(
+            position_hash(&self.board, self.board_size),
+            &Occupant::Black,
+        )];
+        self.captures = (0, 0);
+        self.move_count = 0;
+        self.last_move_passed = false;
+        self.undo_stack.clear();
+    }
+
+    /// Parses a vertex, returning None for a pass.
+    fn parse_vertex(&self, vertex: &str) -> Result<Option<Point>, String> {
+        if vertex.eq_ignore_ascii_case("pass") {
+            return Ok(None);
+        }
+        Point::from_gtp(vertex, self.board_size)
+            .map(Some)
+            .map_err(|_| "invalid coordinate".to_string())
+    }
+
+    fn check_board_empty(&self) -> Result<(), String> {
+        if self.board.iter().any(|spot| spot.occupant != Occupant::Empty) {
+            return Err("board not empty".to_string());
+        }
+        Ok(())
+    }
+
+    /// Places Black's handicap stones, after which White is to move.
+    ///
+    /// # Returns
+    /// * `Ok(vertices)` - The handicap stones as a list of vertices
+    /// * `Err(message)` - If a stone cannot be placed
+    fn place_handicap(&mut self, points: &[Point]) -> Result<String, String> {
+        let mut board = self.board.clone();
+        for &point in points {
+            board = place_setup_stone(board, self.board_size, Occupant::Black, point)?;
+        }
+        self.board = board;
+        self.position_history = vec![position_key(
+            position_hash(&self.board, self.board_size),
+            &Occupant::White,
+        )];
+        let vertices: Vec<String> = points.iter().map(|p| p.to_gtp(self.board_size)).collect();
+        Ok(vertices.join(" "))
+    }
+
+    /// Plays a stone, or passes if `point` is None.
+    fn play(&mut self, color: Occupant, point: Option<Point>) -> Result<(), String> {
+        let opponent = opponent(&color);
+        let snapshot = Snapshot {
+            board: self.board.clone(),
+            position_history: self.position_history.clone(),
+            captures: self.captures,
+            move_count: self.move_count,
+            last_move_passed: self.last_move_passed,
+        };
+
+        if let Some(point) = point {
+            let (new_board, _, effect) = apply_move_to_board(
+                self.board.clone(),
+                self.board_size,
+                color.clone(),
+                point,
+                &self.position_history,
+                self.ruleset,
+                self.move_count + 1,
+            )?;
+            self.board = new_board;
+
+            // Count captured stones as prisoners; stones lost to suicide go to the opponent
+            let captured = effect.captured.len() as u32;
+            let suicided = effect.suicided.len() as u32;
+            if color == Occupant::Black {
+                self.captures.0 += captured;
+                self.captures.1 += suicided;
+            } else {
+                self.captures.1 += captured;
+                self.captures.0 += suicided;
+            }
+        }
+        self.position_history.push(position_key(
+            position_hash(&self.board, self.board_size),
+            &opponent,
+        ));
+        self.move_count += 1;
+        self.last_move_passed = point.is_none();
+        self.undo_stack.push(snapshot);
+        Ok(())
+    }
+
+    /// Lists the groups with the given status ("alive", "dead" or "seki"), one group
+    /// per line.
+    fn final_status_list(&self, status: &str) -> Result<String, String> {
+        let board = Board::new(self.board.clone(), self.board_size);
+        let dead = match self.ruleset.scoring_method() {
+            ScoringMethod::TrompTaylor => Vec::new(),
+            _ => find_dead_stones(&board),
+        };
+        let mut alive_board = board.clone();
+        remove_marked_stones(&mut alive_board, &dead);
+        let seki = find_seki(&alive_board);
+
+        if !matches!(status, "alive" | "dead" | "seki") {
+            return Err("syntax error".to_string());
+        }
+        let mut lines = Vec::new();
+        for group in find_groups(&board) {
+            let first = group.stones[0];
+            let group_status = if dead.contains(&first) {
+                "dead"
+            } else if seki.chains.iter().any(|chain| chain.stones.contains(&first)) {
+                "seki"
+            } else {
+                "alive"
+            };
+            if group_status == status {
+                let vertices: Vec<String> =
+                    group.stones.iter().map(|p| p.to_gtp(self.board_size)).collect();
+                lines.push(vertices.join(" "));
+            }
+        }
+        Ok(lines.join("\n"))
+    }
+
+    /// Draws the board, with Black as `X` and White as `O`.
+    fn showboard(&self) -> String {
+        let size = self.board_size;
+        let letters: String = (0..size)
+            .map(|col| format!(" {}", &Point::new(0, col).to_gtp(size)[..1]))
+            .collect();
+        let mut diagram = format!("\n   {}\n", letters.trim_start());
+        for row in 0..size {
+            let number = size - row;
+            let stones: Vec<&str> = (0..size)
+                .map(|col| match self.board[Point::new(row, col).index(size)].occupant {
+                    Occupant::Black => "X",
+                    Occupant::White => "O",
+                    Occupant::Empty => ".",
+                })
+                .collect();
+            diagram.push_str(&format!("{:>2} {} {}\n", number, stones.join(" "), number));
+        }
+        diagram.push_str(&format!("   {}\n", letters.trim_start()));
+        diagram.push_str(&format!(
+            "Captures: Black (X) {}, White (O) {}",
+            self.captures.0, self.captures.1
+        ));
+        diagram
+    }
+}
+
+impl Default for GtpEngine {
+    fn default() -> Self {
+        GtpEngine::new(Ruleset::default())
+    }
+}
+
+/// Returns the other player.
+fn opponent(color: &Occupant) -> Occupant {
+    if *color == Occupant::Black {
+        Occupant::White
+    } else {
+        Occupant::Black
+    }
+}
+
+/// Parses a color argument such as "b" or "white".
+fn parse_color(arg: Option<&&str>) -> Result<Occupant, String> {
+    match arg.map(|arg| arg.to_ascii_lowercase()).as_deref() {
+        Some("b") | Some("black") => Ok(Occupant::Black),
+        Some("w") | Some("white") => Ok(Occupant::White),
+        _ => Err("invalid color".to_string()),
+    }
+}
+
+/// Parses the argument at `index`, failing with GTP's "syntax error".
+fn parse_arg<T: std::str::FromStr>(args: &[&str], index: usize) -> Result<T, String> {
+    args.get(index)
+        .and_then(|arg| arg.parse().ok())
+        .ok_or_else(|| "syntax error".to_string())
+}
+
+/// Runs a GTP session, reading commands from `input` and writing responses to
+/// `output` until the controller quits or the input ends.
+///
+/// # Arguments
+/// * `engine` - The engine to drive
+/// * `input` - Where commands are read from, usually standard input
+/// * `output` - Where responses are written, usually standard output
+pub fn run_gtp<R: BufRead, W: Write>(
+    engine: &mut GtpEngine,
+    input: R,
+    mut output: W,
+) -> io::Result<()> {
+    for line in input.lines() {
+        if let Some(response) = engine.respond(&line?) {
+            output.write_all(response.as_bytes())?;
+            output.flush()?;
+        }
+        if engine.has_quit() {
+            break;
+        }
+    }
+    Ok(())
+}
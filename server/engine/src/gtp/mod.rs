@@ -0,0 +1,12 @@
+/*!
+ * Go Text Protocol (GTP) support.
+ *
+ * GTP is the text protocol Go programs use to talk to each other. This module
+ * wraps the rules engine in a GTP version 2 engine, so that it can be used from
+ * GUIs such as Sabaki and checked against GTP-based regression suites. The
+ * `gtp` binary runs it on standard input and output.
+ */
+
+pub mod engine;
+
+pub use engine::*;
@@ -0,0 +1,38 @@
+/*!
+ * Go Rules Engine
+ *
+ * The rules of the board game Go (also known as Baduk or Weiqi), with no
+ * dependency on SpacetimeDB, so that the same rules can be used by the game
+ * server, offline tooling, and bots.
+ *
+ * This crate provides:
+ * - Board and position representation
+ * - Move validation, captures, and ko/superko under selectable rulesets
+ * - Group, liberty, and territory analysis
+ * - Scoring calculations
+ * - SGF game records
+ * - A computer opponent
+ * - A Go Text Protocol (GTP) engine
+ */
+
+pub mod bot;
+pub mod gtp;
+pub mod models;
+pub mod scoring;
+pub mod sgf;
+pub mod utils;
+
+#[cfg(test)]
+pub mod tests;
+
+// Re-export the public items from each module
+pub use bot::*;
+pub use gtp::*;
+pub use models::*;
+pub use scoring::*;
+pub use sgf::*;
+pub use utils::*;
+
+// Export test modules for testing
+#[cfg(test)]
+pub use tests::*;
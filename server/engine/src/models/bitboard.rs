@@ -0,0 +1,286 @@
+use super::point::Point;
+use super::zobrist::MAX_BOARD_SIZE;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, Not};
+
+/// Number of 64-bit words needed to hold one bit per point of the largest board.
+const WORDS: usize = ((MAX_BOARD_SIZE as usize) * (MAX_BOARD_SIZE as usize)).div_ceil(64);
+
+/// A set of board points stored as one bit per point, in row-major order.
+///
+/// Bitboards are fixed-size and `Copy`, so set operations, shifts and flood fills
+/// run without allocating. Bit `i` corresponds to the same flat index used for
+/// `Board::spots`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bitboard([u64; WORDS]);
+
+impl Bitboard {
+    /// The bitboard with no points set.
+    pub const EMPTY: Bitboard = Bitboard([0; WORDS]);
+
+    /// Returns a bitboard containing only the point at `idx`.
+    pub fn single(idx: usize) -> Self {
+        let mut bb = Bitboard::EMPTY;
+        bb.insert(idx);
+        bb
+    }
+
+    /// Returns whether the point at `idx` is set.
+    pub fn contains(&self, idx: usize) -> bool {
+        self.0[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    /// Sets the point at `idx`.
+    pub fn insert(&mut self, idx: usize) {
+        self.0[idx / 64] |= 1 << (idx % 64);
+    }
+
+    /// Clears the point at `idx`.
+    pub fn remove(&mut self, idx: usize) {
+        self.0[idx / 64] &= !(1 << (idx % 64));
+    }
+
+    /// Returns whether no point is set.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    /// Returns the number of points set.
+    pub fn count(&self) -> u32 {
+        self.0.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Returns the lowest set point, if any.
+    pub fn first(&self) -> Option<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .find(|(_, &word)| word != 0)
+            .map(|(i, word)| i * 64 + word.trailing_zeros() as usize)
+    }
+
+    /// Returns an iterator over the indices of the set points, in increasing order.
+    pub fn iter(&self) -> BitIter {
+        BitIter {
+            words: self.0,
+            word: 0,
+        }
+    }
+
+    /// Returns an iterator over the set points as [`Point`]s of a board of the given size.
+    pub fn points(&self, board_size: u8) -> impl Iterator<Item = Point> {
+        let size = board_size as usize;
+        self.iter()
+            .map(move |idx| Point::new((idx / size) as u8, (idx % size) as u8))
+    }
+
+    /// Moves every point `n` indices up (towards higher indices), with `n < 64`.
+    fn shift_up(self, n: usize) -> Self {
+        let mut out = [0u64; WORDS];
+        for (i, word) in out.iter_mut().enumerate() {
+            *word = self.0[i] << n;
+            if i > 0 && n > 0 {
+                *word |= self.0[i - 1] >> (64 - n);
+            }
+        }
+        Bitboard(out)
+    }
+
+    /// Moves every point `n` indices down (towards lower indices), with `n < 64`.
+    fn shift_down(self, n: usize) -> Self {
+        let mut out = [0u64; WORDS];
+        for (i, word) in out.iter_mut().enumerate() {
+            *word = self.0[i] >> n;
+            if i + 1 < WORDS && n > 0 {
+                *word |= self.0[i + 1] << (64 - n);
+            }
+        }
+        Bitboard(out)
+    }
+}
+
+/// Iterator over the set points of a [`Bitboard`].
+pub struct BitIter {
+    words: [u64; WORDS],
+    word: usize,
+}
+
+impl Iterator for BitIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word < WORDS {
+            let bits = self.words[self.word];
+            if bits != 0 {
+                self.words[self.word] = bits & (bits - 1);
+                return Some(self.word * 64 + bits.trailing_zeros() as usize);
+            }
+            self.word += 1;
+        }
+        None
+    }
+}
+
+impl FromIterator<usize> for Bitboard {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut bb = Bitboard::EMPTY;
+        for idx in iter {
+            bb.insert(idx);
+        }
+        bb
+    }
+}
+
+impl BitAnd for Bitboard {
+    type Output = Bitboard;
+
+    fn bitand(mut self, rhs: Bitboard) -> Bitboard {
+        self &= rhs;
+        self
+    }
+}
+
+impl BitAndAssign for Bitboard {
+    fn bitand_assign(&mut self, rhs: Bitboard) {
+        for (word, other) in self.0.iter_mut().zip(rhs.0) {
+            *word &= other;
+        }
+    }
+}
+
+impl BitOr for Bitboard {
+    type Output = Bitboard;
+
+    fn bitor(mut self, rhs: Bitboard) -> Bitboard {
+        self |= rhs;
+        self
+    }
+}
+
+impl BitOrAssign for Bitboard {
+    fn bitor_assign(&mut self, rhs: Bitboard) {
+        for (word, other) in self.0.iter_mut().zip(rhs.0) {
+            *word |= other;
+        }
+    }
+}
+
+impl BitXor for Bitboard {
+    type Output = Bitboard;
+
+    fn bitxor(mut self, rhs: Bitboard) -> Bitboard {
+        for (word, other) in self.0.iter_mut().zip(rhs.0) {
+            *word ^= other;
+        }
+        self
+    }
+}
+
+impl Not for Bitboard {
+    type Output = Bitboard;
+
+    /// Complements every bit, including bits beyond the board; mask the result with
+    /// [`Geometry::all`] when only on-board points matter.
+    fn not(mut self) -> Bitboard {
+        for word in self.0.iter_mut() {
+            *word = !*word;
+        }
+        self
+    }
+}
+
+/// Precomputed masks for a board of a given size, used by shift-based operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Geometry {
+    /// The size of the board (e.g., 9 for a 9×9 board)
+    board_size: u8,
+
+    /// Every point on the board
+    all: Bitboard,
+
+    /// Every point except those in the first column
+    not_first_col: Bitboard,
+
+    /// Every point except those in the last column
+    not_last_col: Bitboard,
+
+    /// Every point on the outer edge of the board
+    edge: Bitboard,
+}
+
+impl Geometry {
+    /// Builds the masks for a board of the given size.
+    ///
+    /// # Panics
+    /// Panics if `board_size` is zero or exceeds [`MAX_BOARD_SIZE`].
+    pub fn new(board_size: u8) -> Self {
+        assert!(
+            board_size > 0 && board_size <= MAX_BOARD_SIZE,
+            "Board size {} is not between 1 and {}",
+            board_size,
+            MAX_BOARD_SIZE
+        );
+
+        let size = board_size as usize;
+        let mut geometry = Geometry {
+            board_size,
+            all: Bitboard::EMPTY,
+            not_first_col: Bitboard::EMPTY,
+            not_last_col: Bitboard::EMPTY,
+            edge: Bitboard::EMPTY,
+        };
+        for idx in 0..size * size {
+            let (row, col) = (idx / size, idx % size);
+            geometry.all.insert(idx);
+            if col != 0 {
+                geometry.not_first_col.insert(idx);
+            }
+            if col != size - 1 {
+                geometry.not_last_col.insert(idx);
+            }
+            if row == 0 || row == size - 1 || col == 0 || col == size - 1 {
+                geometry.edge.insert(idx);
+            }
+        }
+        geometry
+    }
+
+    /// Returns the size of the board.
+    pub fn board_size(&self) -> u8 {
+        self.board_size
+    }
+
+    /// Returns every point on the board.
+    pub fn all(&self) -> Bitboard {
+        self.all
+    }
+
+    /// Returns every point on the outer edge of the board.
+    pub fn edge(&self) -> Bitboard {
+        self.edge
+    }
+
+    /// Returns the points orthogonally adjacent to any point of `bb`, excluding `bb` itself.
+    pub fn adjacent(&self, bb: Bitboard) -> Bitboard {
+        let size = self.board_size as usize;
+        let spread = (bb.shift_up(1) & self.not_first_col)
+            | (bb.shift_down(1) & self.not_last_col)
+            | bb.shift_up(size)
+            | bb.shift_down(size);
+        spread & self.all & !bb
+    }
+
+    /// Returns every point of `within` connected to `seed` through orthogonally
+    /// adjacent points of `within`.
+    ///
+    /// Points of `seed` outside `within` are ignored.
+    pub fn flood_fill(&self, seed: Bitboard, within: Bitboard) -> Bitboard {
+        let mut filled = seed & within;
+        loop {
+            let grown = (filled | self.adjacent(filled)) & within;
+            if grown == filled {
+                return filled;
+            }
+            filled = grown;
+        }
+    }
+}
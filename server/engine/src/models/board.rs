@@ -0,0 +1,291 @@
+use super::chains::Chains;
+use super::point::Point;
+use super::ruleset::{Ruleset, ScoringMethod};
+use super::spot::{GroupStatus, Occupant, SpotState};
+use super::zobrist::{hash_spots, size_key, stone_key};
+use crate::scoring::{find_empty_regions, find_pass_alive, find_seki};
+
+/// Maps a (row, col) coordinate onto its image under one of the board's symmetries,
+/// given the index of the last row/column.
+type Symmetry = fn(u8, u8, u8) -> (u8, u8);
+
+/// Represents a Go game board with its current state.
+///
+/// The board consists of a grid of spots arranged in a square. Each spot can be
+/// empty or occupied by a black or white stone. The board is stored as a flat vector
+/// in row-major order (i.e., each row is stored consecutively).
+///
+/// The board also carries a Zobrist hash of its stone placement, which is updated
+/// incrementally by [`Board::set_occupant`] so that positions can be compared in O(1).
+/// Code that edits `spots` directly must call [`Board::refresh_hash`] afterwards.
+#[derive(Clone, Debug)]
+pub struct Board {
+    /// The size of the board (e.g., 9 for a 9×9 board)
+    pub board_size: u8,
+
+    /// The state of each spot on the board, stored in row-major order
+    pub spots: Vec<SpotState>,
+
+    /// Zobrist hash of the current stone placement
+    hash: u64,
+}
+
+impl Board {
+    /// Creates a new board from a vector of SpotState and a given board size.
+    ///
+    /// # Arguments
+    /// * `spots` - Vector of spot states, must contain board_size² elements
+    /// * `board_size` - Size of the board (e.g., 9, 13, 19)
+    ///
+    /// # Panics
+    /// Panics if the number of spots does not equal board_size².
+    pub fn new(spots: Vec<SpotState>, board_size: u8) -> Self {
+        assert_eq!(
+            spots.len(),
+            (board_size as usize).pow(2),
+            "Invalid board: contains {} spots but expected {} for size {}",
+            spots.len(),
+            (board_size as usize).pow(2),
+            board_size
+        );
+        let hash = hash_spots(&spots, board_size);
+        Board {
+            board_size,
+            spots,
+            hash,
+        }
+    }
+
+    /// Returns the Zobrist hash identifying the current whole-board position.
+    ///
+    /// Two boards of the same size with identical stone placement always have the
+    /// same hash, regardless of markers or scoring annotations.
+    pub fn position_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns a hash that is identical for all eight rotations and reflections of
+    /// the current position.
+    ///
+    /// Useful for recognising the same shape across games, e.g. in an opening database.
+    pub fn canonical_hash(&self) -> u64 {
+        let last = self.board_size.saturating_sub(1);
+        let transforms: [Symmetry; 8] = [
+            |r, c, _| (r, c),
+            |r, c, n| (c, n - r),
+            |r, c, n| (n - r, n - c),
+            |r, c, n| (n - c, r),
+            |r, c, n| (r, n - c),
+            |r, c, n| (n - r, c),
+            |r, c, _| (c, r),
+            |r, c, n| (n - c, n - r),
+        ];
+
+        transforms
+            .iter()
+            .map(|transform| {
+                let mut hash = size_key(self.board_size);
+                for row in 0..self.board_size {
+                    for col in 0..self.board_size {
+                        let occupant = &self.spots[self.index(Point::new(row, col))].occupant;
+                        let (tr, tc) = transform(row, col, last);
+                        hash ^= stone_key(tr, tc, occupant);
+                    }
+                }
+                hash
+            })
+            .min()
+            .unwrap()
+    }
+
+    /// Sets the occupant of the spot at a point, keeping the position hash up to date.
+    ///
+    /// # Arguments
+    /// * `point` - The point to change
+    /// * `occupant` - The new occupant of the spot
+    ///
+    /// # Panics
+    /// Panics if the point is out of bounds.
+    pub fn set_occupant(&mut self, point: Point, occupant: Occupant) {
+        let idx = self.index(point);
+        self.hash ^= stone_key(point.row, point.col, &self.spots[idx].occupant);
+        self.hash ^= stone_key(point.row, point.col, &occupant);
+        self.spots[idx].occupant = occupant;
+    }
+
+    /// Recomputes the position hash from scratch.
+    ///
+    /// Only needed after modifying `spots` directly instead of through [`Board::set_occupant`].
+    pub fn refresh_hash(&mut self) {
+        self.hash = hash_spots(&self.spots, self.board_size);
+    }
+
+    /// Converts a point to the corresponding index in the spots vector.
+    ///
+    /// # Arguments
+    /// * `point` - The point to convert
+    pub fn index(&self, point: Point) -> usize {
+        point.index(self.board_size)
+    }
+
+    /// Gets an immutable reference to the spot at a point if it is within bounds.
+    ///
+    /// # Arguments
+    /// * `point` - The point to look up
+    ///
+    /// # Returns
+    /// * `Some(&SpotState)` - Reference to the spot if the point is on the board
+    /// * `None` - If the point is out of bounds
+    pub fn get(&self, point: Point) -> Option<&SpotState> {
+        if point.is_on_board(self.board_size) {
+            Some(&self.spots[self.index(point)])
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the spot at a point if it is within bounds.
+    ///
+    /// # Arguments
+    /// * `point` - The point to look up
+    ///
+    /// # Returns
+    /// * `Some(&mut SpotState)` - Mutable reference to the spot if the point is on the board
+    /// * `None` - If the point is out of bounds
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut SpotState> {
+        if point.is_on_board(self.board_size) {
+            let idx = self.index(point);
+            Some(&mut self.spots[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Returns all valid orthogonal neighbors of a given point.
+    ///
+    /// In Go, only orthogonally adjacent positions (not diagonals) are considered connected.
+    ///
+    /// # Arguments
+    /// * `point` - The point whose neighbors to return
+    pub fn neighbors(&self, point: Point) -> Vec<Point> {
+        point.neighbors(self.board_size)
+    }
+
+    /// Annotates each empty spot with scoring metadata based on territory analysis.
+    ///
+    /// This function updates the `scoring_owner` and `scoring_explanation` fields in-place.
+    /// Only empty regions are annotated because they are the only ones that can be scored.
+    /// Shared liberties and eyes of groups in seki are explained as such; the eyes are
+    /// only owned under area scoring.
+    ///
+    /// # Arguments
+    /// * `method` - The scoring method the game is counted with
+    pub fn annotate_for_scoring(&mut self, method: ScoringMethod) {
+        // Clear any previous scoring info.
+        for spot in self.spots.iter_mut() {
+            spot.scoring_owner = None;
+            spot.scoring_explanation = None;
+        }
+
+        let regions = find_empty_regions(self);
+        log::info!("Found {} empty regions", regions.len());
+        let seki = find_seki(self);
+
+        for region in regions {
+            if region.spots.iter().any(|&point| seki.shared_liberties.contains(&point)) {
+                // Liberties shared by groups in seki, which neither player can fill
+                for point in region.spots {
+                    if let Some(spot) = self.get_mut(point) {
+                        spot.scoring_owner = None;
+                        spot.scoring_explanation = Some("Seki (shared liberty)".to_string());
+                    }
+                }
+            } else if seki.contains(region.spots[0]) {
+                // Eye of a group in seki - only counts under area scoring
+                let owner = match method {
+                    ScoringMethod::Area | ScoringMethod::TrompTaylor => {
+                        region.border.iter().next().cloned()
+                    }
+                    ScoringMethod::Territory => None,
+                };
+                for point in region.spots {
+                    if let Some(spot) = self.get_mut(point) {
+                        spot.scoring_owner = owner.clone();
+                        spot.scoring_explanation = Some("Seki (eye)".to_string());
+                    }
+                }
+            } else if region.border.len() == 1 {
+                // Region surrounded by stones of just one color - counts as territory for that color
+                let owner = region.border.iter().next().unwrap().clone();
+                for point in region.spots {
+                    if let Some(spot) = self.get_mut(point) {
+                        spot.scoring_owner = Some(owner.clone());
+                        spot.scoring_explanation = Some(format!("Cell enclosed by {:?}", owner));
+                    }
+                }
+            } else {
+                // Region with mixed borders - neutral points ("dame")
+                for point in region.spots {
+                    if let Some(spot) = self.get_mut(point) {
+                        spot.scoring_owner = None;
+                        spot.scoring_explanation = Some("Neutral".to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Annotates each stone with its group status, as decided by Benson's algorithm.
+    ///
+    /// Stones of pass-alive chains are marked alive, and stones inside the regions those
+    /// chains make vital are marked dead. Stones whose status cannot be decided without
+    /// reading are left unannotated.
+    pub fn annotate_group_status(&mut self) {
+        for spot in self.spots.iter_mut() {
+            spot.group_status = None;
+        }
+
+        for color in [Occupant::Black, Occupant::White] {
+            let pass_alive = find_pass_alive(self, &color);
+            for point in pass_alive.chains.iter().flat_map(|chain| &chain.stones) {
+                if let Some(spot) = self.get_mut(*point) {
+                    spot.group_status = Some(GroupStatus::Alive);
+                }
+            }
+            for point in pass_alive.vital_regions.iter().flatten() {
+                if let Some(spot) = self.get_mut(*point) {
+                    if spot.occupant != Occupant::Empty {
+                        spot.group_status = Some(GroupStatus::Dead);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Annotates each empty spot with a "playable" flag based on move legality.
+    ///
+    /// A move is legal if:
+    /// 1. The spot is empty
+    /// 2. The resulting group has at least one liberty, or captures an enemy group,
+    ///    or the ruleset allows suicide
+    /// 3. The move doesn't violate the ruleset's ko rule
+    ///
+    /// Legality is computed from incrementally built [`Chains`], so every point is
+    /// checked in constant time rather than by simulating the move.
+    ///
+    /// # Arguments
+    /// * `current_turn` - Which player is currently moving
+    /// * `ruleset` - The ruleset the game is played under
+    /// * `position_history` - Position keys of every position so far, ending with the current one
+    pub fn annotate_playability(
+        &mut self,
+        current_turn: Occupant,
+        ruleset: Ruleset,
+        position_history: &[u64],
+    ) {
+        let legal = Chains::from_board(self).legal_moves(&current_turn, ruleset, position_history);
+        for (spot, playable) in self.spots.iter_mut().zip(legal) {
+            spot.playable = playable;
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use super::bitboard::Bitboard;
+use super::board::Board;
+use super::position::Position;
+use super::ruleset::Ruleset;
+use super::spot::Occupant;
+use super::zobrist::stone_key;
+
+/// Parent value of points that hold no stone.
+const NO_CHAIN: usize = usize::MAX;
+
+/// Incrementally maintained chains (groups) of stones and their liberties.
+///
+/// Chains are kept in a union-find structure over the board's points: every stone
+/// points towards the representative ("root") stone of its chain, and each root
+/// stores the chain's stones and liberties as bitboards, plus its Zobrist hash.
+/// Placing a stone merges it with adjacent friendly chains, and capturing a chain
+/// hands its points back as liberties to the neighbouring chains, so no full-board
+/// search is ever needed.
+///
+/// With liberty counts available in O(1), the legality of a move (including the
+/// position it would produce, for ko checking) can be decided without simulating it,
+/// which makes computing legality for every point near-linear in the board size.
+#[derive(Debug)]
+pub struct Chains {
+    /// The stones on the board
+    position: Position,
+
+    /// Union-find parent of each stone, or `NO_CHAIN` for empty points
+    parent: Vec<usize>,
+
+    /// Stones of each chain, stored at the chain's root
+    stones: Vec<Bitboard>,
+
+    /// Liberties of each chain, stored at the chain's root
+    liberties: Vec<Bitboard>,
+
+    /// XOR of the Zobrist keys of each chain's stones, stored at the chain's root
+    chain_hashes: Vec<u64>,
+}
+
+/// The stones removed from the board by a move played through [`Chains::play`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MoveEffect {
+    /// Indices of opponent stones captured by the move
+    pub captured: Vec<usize>,
+
+    /// Indices of the player's own stones removed by a (legal) suicide
+    pub suicided: Vec<usize>,
+}
+
+impl Clone for Chains {
+    fn clone(&self) -> Self {
+        Chains {
+            position: self.position,
+            parent: self.parent.clone(),
+            stones: self.stones.clone(),
+            liberties: self.liberties.clone(),
+            chain_hashes: self.chain_hashes.clone(),
+        }
+    }
+
+    /// Copies `source` into the buffers this value already has, so a copy that is
+    /// reset over and over (as in a search's playouts) never reallocates.
+    fn clone_from(&mut self, source: &Self) {
+        self.position = source.position;
+        self.parent.clone_from(&source.parent);
+        self.stones.clone_from(&source.stones);
+        self.liberties.clone_from(&source.liberties);
+        self.chain_hashes.clone_from(&source.chain_hashes);
+    }
+}
+
+impl Chains {
+    /// Creates chain tracking for an empty board of the given size.
+    pub fn new(board_size: u8) -> Self {
+        let points = (board_size as usize).pow(2);
+        Chains {
+            position: Position::new(board_size),
+            parent: vec![NO_CHAIN; points],
+            stones: vec![Bitboard::EMPTY; points],
+            liberties: vec![Bitboard::EMPTY; points],
+            chain_hashes: vec![0; points],
+        }
+    }
+
+    /// Builds chain tracking for the stones of a position.
+    ///
+    /// Stones are added one by one without capturing, so this runs in near-linear
+    /// time in the number of points.
+    pub fn from_position(position: &Position) -> Self {
+        let mut chains = Chains::new(position.board_size());
+        for color in [Occupant::Black, Occupant::White] {
+            for idx in position.stones(&color).iter() {
+                chains.add_stone(idx, color.clone());
+            }
+        }
+        chains
+    }
+
+    /// Builds chain tracking for the stones currently on a board.
+    pub fn from_board(board: &Board) -> Self {
+        Chains::from_position(&Position::from_board(board))
+    }
+
+    /// Returns the size of the board.
+    pub fn board_size(&self) -> u8 {
+        self.position.board_size()
+    }
+
+    /// Returns the current position.
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    /// Returns what occupies every point, in row-major order.
+    pub fn occupants(&self) -> Vec<Occupant> {
+        self.position.occupants()
+    }
+
+    /// Returns the Zobrist hash of the current position.
+    pub fn position_hash(&self) -> u64 {
+        self.position.position_hash()
+    }
+
+    /// Returns the number of liberties of the chain containing the stone at `idx`,
+    /// or `None` if the point is empty.
+    pub fn liberty_count(&self, idx: usize) -> Option<usize> {
+        self.root(idx)
+            .map(|root| self.liberties[root].count() as usize)
+    }
+
+    /// Returns the liberties of the chain containing the stone at `idx`,
+    /// or `None` if the point is empty.
+    pub fn chain_liberties(&self, idx: usize) -> Option<Bitboard> {
+        self.root(idx).map(|root| self.liberties[root])
+    }
+
+    /// Returns the stones of the chain containing the stone at `idx`,
+    /// or `None` if the point is empty.
+    pub fn chain_stones(&self, idx: usize) -> Option<Bitboard> {
+        self.root(idx).map(|root| self.stones[root])
+    }
+
+    /// Checks whether `color` may play at `idx` without actually playing.
+    ///
+    /// # Arguments
+    /// * `idx` - Index of the point to play on
+    /// * `color` - Color of the stone to place
+    /// * `ruleset` - The ruleset deciding suicide and ko legality
+    /// * `position_history` - Position keys of every earlier position in the game
+    ///
+    /// # Returns
+    /// * `Ok(hash)` - The Zobrist hash of the position the move would produce
+    /// * `Err(message)` - Why the move is illegal
+    pub fn check_move(
+        &self,
+        idx: usize,
+        color: &Occupant,
+        ruleset: Ruleset,
+        position_history: &[u64],
+    ) -> Result<u64, String> {
+        if self.position.occupant(idx) != Occupant::Empty {
+            return Err("Position already occupied".to_string());
+        }
+        let opponent = match color {
+            Occupant::Black => Occupant::White,
+            Occupant::White => Occupant::Black,
+            Occupant::Empty => return Err("Cannot play an empty stone".to_string()),
+        };
+
+        let mut new_hash = self.position_hash() ^ self.key(idx, color);
+        let mut has_liberty = false;
+        let mut friendly_roots: Vec<usize> = Vec::with_capacity(4);
+        let mut captured_roots: Vec<usize> = Vec::with_capacity(4);
+
+        for n in self.neighbors(idx).iter() {
+            let Some(root) = self.root(n) else {
+                has_liberty = true;
+                continue;
+            };
+            if self.position.occupant(n) == *color {
+                // A friendly chain keeps a liberty if it has one besides this point
+                if self.liberties[root].count() > 1 {
+                    has_liberty = true;
+                }
+                if !friendly_roots.contains(&root) {
+                    friendly_roots.push(root);
+                }
+            } else if self.liberties[root].count() == 1 && !captured_roots.contains(&root) {
+                // This point is the enemy chain's last liberty, so it is captured
+                captured_roots.push(root);
+                new_hash ^= self.chain_hashes[root];
+            }
+        }
+
+        if !has_liberty && captured_roots.is_empty() {
+            if !ruleset.allows_suicide() {
+                return Err("Illegal move: self_capture".to_string());
+            }
+            // The new stone and every chain it joins are removed
+            new_hash ^= self.key(idx, color);
+            for root in friendly_roots {
+                new_hash ^= self.chain_hashes[root];
+            }
+        }
+
+        ruleset.check_repetition(position_history, new_hash, &opponent)?;
+        Ok(new_hash)
+    }
+
+    /// Returns, for every point, whether `color` may legally play there.
+    ///
+    /// # Arguments
+    /// * `color` - The player to move
+    /// * `ruleset` - The ruleset deciding suicide and ko legality
+    /// * `position_history` - Position keys of every earlier position in the game
+    pub fn legal_moves(
+        &self,
+        color: &Occupant,
+        ruleset: Ruleset,
+        position_history: &[u64],
+    ) -> Vec<bool> {
+        let points = (self.board_size() as usize).pow(2);
+        (0..points)
+            .map(|idx| {
+                self.check_move(idx, color, ruleset, position_history)
+                    .is_ok()
+            })
+            .collect()
+    }
+
+    /// Plays a stone at `idx`, capturing opponent chains left without liberties and,
+    /// if the player's own chain is then without liberties, removing it as suicide.
+    ///
+    /// This does not check legality; use [`Chains::check_move`] first.
+    ///
+    /// # Arguments
+    /// * `idx` - Index of an empty point
+    /// * `color` - Color of the stone to place
+    ///
+    /// # Returns
+    /// The stones removed from the board by the move
+    pub fn play(&mut self, idx: usize, color: Occupant) -> MoveEffect {
+        let mut effect = MoveEffect::default();
+        self.add_stone(idx, color.clone());
+
+        for n in self.neighbors(idx).iter() {
+            let occupant = self.position.occupant(n);
+            if occupant == Occupant::Empty || occupant == color {
+                continue;
+            }
+            let root = self.find(n);
+            if self.liberties[root].is_empty() {
+                effect.captured.extend(self.remove_chain(root).iter());
+            }
+        }
+
+        let own_root = self.find(idx);
+        if self.liberties[own_root].is_empty() {
+            effect.suicided = self.remove_chain(own_root).iter().collect();
+        }
+
+        effect
+    }
+
+    /// Places a stone without resolving captures, merging it into adjacent chains.
+    fn add_stone(&mut self, idx: usize, color: Occupant) {
+        self.position.set(idx, &color);
+        self.parent[idx] = idx;
+        self.stones[idx] = Bitboard::single(idx);
+        self.chain_hashes[idx] = self.key(idx, &color);
+
+        let neighbors = self.neighbors(idx);
+        self.liberties[idx] = neighbors & self.position.empty();
+
+        let mut root = idx;
+        for n in (neighbors & !self.position.empty()).iter() {
+            let neighbor_root = self.find(n);
+            self.liberties[neighbor_root].remove(idx);
+            if self.position.occupant(n) == color {
+                root = self.union(root, neighbor_root);
+            }
+        }
+    }
+
+    /// Removes every stone of the chain rooted at `root`, returning them.
+    ///
+    /// The freed points become liberties of the neighbouring chains.
+    fn remove_chain(&mut self, root: usize) -> Bitboard {
+        let stones = std::mem::take(&mut self.stones[root]);
+        self.liberties[root] = Bitboard::EMPTY;
+        self.chain_hashes[root] = 0;
+        self.position.remove(stones);
+
+        for stone in stones.iter() {
+            self.parent[stone] = NO_CHAIN;
+        }
+        let bordering = self.position.geometry().adjacent(stones) & !self.position.empty();
+        for n in bordering.iter() {
+            let neighbor_root = self.find(n);
+            let freed = self.neighbors(n) & stones;
+            self.liberties[neighbor_root] |= freed;
+        }
+
+        stones
+    }
+
+    /// Merges the chains rooted at `a` and `b`, returning the root of the merged chain.
+    ///
+    /// The chain with fewer stones is merged into the larger one (union by size).
+    fn union(&mut self, a: usize, b: usize) -> usize {
+        if a == b {
+            return a;
+        }
+        let (big, small) = if self.stones[a].count() >= self.stones[b].count() {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        self.parent[small] = big;
+        let small_stones = std::mem::take(&mut self.stones[small]);
+        self.stones[big] |= small_stones;
+        let small_liberties = std::mem::take(&mut self.liberties[small]);
+        self.liberties[big] |= small_liberties;
+        self.chain_hashes[big] ^= std::mem::take(&mut self.chain_hashes[small]);
+
+        big
+    }
+
+    /// Returns the root of the chain containing `idx`, compressing the path on the way.
+    fn find(&mut self, idx: usize) -> usize {
+        let mut current = idx;
+        while self.parent[current] != current {
+            let grandparent = self.parent[self.parent[current]];
+            self.parent[current] = grandparent;
+            current = grandparent;
+        }
+        current
+    }
+
+    /// Returns the root of the chain containing `idx` without modifying the structure,
+    /// or `None` if the point is empty.
+    fn root(&self, idx: usize) -> Option<usize> {
+        if self.parent[idx] == NO_CHAIN {
+            return None;
+        }
+        let mut current = idx;
+        while self.parent[current] != current {
+            current = self.parent[current];
+        }
+        Some(current)
+    }
+
+    /// Returns the Zobrist key of a stone of `color` at `idx`.
+    fn key(&self, idx: usize, color: &Occupant) -> u64 {
+        let size = self.board_size() as usize;
+        stone_key((idx / size) as u8, (idx % size) as u8, color)
+    }
+
+    /// Returns the orthogonal neighbours of `idx`.
+    fn neighbors(&self, idx: usize) -> Bitboard {
+        self.position.geometry().adjacent(Bitboard::single(idx))
+    }
+}
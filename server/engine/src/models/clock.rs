@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+/// How much thinking time each player gets.
+///
+/// Every control starts with a block of main time. What happens once it is used up
+/// depends on the system: absolute time ends the game, byo-yomi and Canadian
+/// overtime give the player further periods, and a Fischer clock gains a fixed
+/// increment after every move instead. All durations are in milliseconds.
+///
+/// Time controls are stored on a game as JSON, tagged by `type`, e.g.
+/// `{"type":"byo_yomi","main_time_ms":600000,"period_ms":30000,"periods":5}`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TimeControl {
+    /// A fixed amount of time for the whole game
+    Absolute { main_time_ms: u64 },
+    /// Japanese byo-yomi: after main time, each move must be made within a period;
+    /// a period is lost only when it is used up completely
+    ByoYomi {
+        main_time_ms: u64,
+        period_ms: u64,
+        periods: u32,
+    },
+    /// Canadian overtime: after main time, a fixed number of stones must be played
+    /// within each period, which then starts over
+    Canadian {
+        main_time_ms: u64,
+        period_ms: u64,
+        stones: u32,
+    },
+    /// Fischer increment: a fixed amount of time is added after every move
+    Fischer { main_time_ms: u64, increment_ms: u64 },
+}
+
+/// One player's clock under a [`TimeControl`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Clock {
+    /// Main time left
+    pub main_time_ms: u64,
+
+    /// Byo-yomi periods left
+    pub periods: u32,
+
+    /// Time left in the current Canadian overtime period
+    pub period_ms: u64,
+
+    /// Stones still to be played in the current Canadian overtime period
+    pub stones: u32,
+}
+
+impl TimeControl {
+    /// Checks that the time control gives the players some time to move.
+    ///
+    /// # Returns
+    /// * `Ok(())` - The time control can be used
+    /// * `Err(message)` - If a required duration or count is zero
+    pub fn validate(&self) -> Result<(), String> {
+        match *self {
+            TimeControl::Absolute { main_time_ms } | TimeControl::Fischer { main_time_ms, .. }
+                if main_time_ms == 0 =>
+            {
+                Err("Main time must be greater than zero".to_string())
+            }
+            TimeControl::ByoYomi {
+                period_ms, periods, ..
+            } if period_ms == 0 || periods == 0 => {
+                Err("Byo-yomi needs at least one period of non-zero length".to_string())
+            }
+            TimeControl::Canadian {
+                period_ms, stones, ..
+            } if period_ms == 0 || stones == 0 => {
+                Err("Canadian overtime needs a non-zero period and stone count".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the clock each player starts the game with.
+    pub fn initial_clock(&self) -> Clock {
+        let mut clock = Clock {
+            main_time_ms: 0,
+            periods: 0,
+            period_ms: 0,
+            stones: 0,
+        };
+        match *self {
+            TimeControl::Absolute { main_time_ms } | TimeControl::Fischer { main_time_ms, .. } => {
+                clock.main_time_ms = main_time_ms;
+            }
+            TimeControl::ByoYomi {
+                main_time_ms,
+                periods,
+                ..
+            } => {
+                clock.main_time_ms = main_time_ms;
+                clock.periods = periods;
+            }
+            TimeControl::Canadian {
+                main_time_ms,
+                period_ms,
+                stones,
+            } => {
+                clock.main_time_ms = main_time_ms;
+                clock.period_ms = period_ms;
+                clock.stones = stones;
+            }
+        }
+        clock
+    }
+}
+
+impl Clock {
+    /// Returns how long the player can think about their next move before their
+    /// flag falls.
+    ///
+    /// # Arguments
+    /// * `control` - The time control this clock runs under
+    pub fn time_remaining(&self, control: &TimeControl) -> u64 {
+        match *control {
+            TimeControl::Absolute { .. } | TimeControl::Fischer { .. } => self.main_time_ms,
+            TimeControl::ByoYomi { period_ms, .. } => {
+                self.main_time_ms + self.periods as u64 * period_ms
+            }
+            TimeControl::Canadian { .. } => self.main_time_ms + self.period_ms,
+        }
+    }
+
+    /// Returns whether the player's flag has fallen after thinking for `elapsed_ms`.
+    pub fn is_flagged(&self, control: &TimeControl, elapsed_ms: u64) -> bool {
+        elapsed_ms >= self.time_remaining(control)
+    }
+
+    /// Charges the time a player spent on a move to their clock.
+    ///
+    /// # Arguments
+    /// * `control` - The time control this clock runs under
+    /// * `elapsed_ms` - How long the player took over the move
+    ///
+    /// # Returns
+    /// * `Ok(())` - The move was made in time and the clock was updated
+    /// * `Err(message)` - If the player ran out of time; the clock is left unchanged
+    pub fn charge_move(&mut self, control: &TimeControl, elapsed_ms: u64) -> Result<(), String> {
+        if self.is_flagged(control, elapsed_ms) {
+            return Err("Out of time".to_string());
+        }
+
+        // Main time is used first; only the overflow counts against overtime
+        let in_main_time = elapsed_ms < self.main_time_ms;
+        let overflow = elapsed_ms.saturating_sub(self.main_time_ms);
+        self.main_time_ms = self.main_time_ms.saturating_sub(elapsed_ms);
+
+        match *control {
+            TimeControl::Absolute { .. } => {}
+            TimeControl::Fischer { increment_ms, .. } => {
+                self.main_time_ms += increment_ms;
+            }
+            TimeControl::ByoYomi { period_ms, .. } => {
+                // Each period used up in full is lost; the one the move was made in
+                // starts over for the next move
+                self.periods -= (overflow / period_ms) as u32;
+            }
+            TimeControl::Canadian {
+                period_ms, stones, ..
+            } => {
+                if !in_main_time {
+                    self.period_ms -= overflow;
+                    self.stones -= 1;
+                    if self.stones == 0 {
+                        self.period_ms = period_ms;
+                        self.stones = stones;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
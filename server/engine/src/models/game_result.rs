@@ -0,0 +1,157 @@
+use super::spot::Occupant;
+use std::fmt;
+use std::str::FromStr;
+
+/// Why a game ended the way it did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResultReason {
+    /// The game was counted and one player had more points
+    Score,
+    /// A player resigned
+    Resignation,
+    /// A player ran out of time
+    Timeout,
+    /// A player forfeited, e.g. by abandoning the game
+    Forfeit,
+    /// The game was counted and the scores were equal
+    Jigo,
+}
+
+impl ResultReason {
+    /// Returns the identifier used to store this reason on a game.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResultReason::Score => "score",
+            ResultReason::Resignation => "resignation",
+            ResultReason::Timeout => "timeout",
+            ResultReason::Forfeit => "forfeit",
+            ResultReason::Jigo => "jigo",
+        }
+    }
+}
+
+/// The outcome of a finished game.
+///
+/// Results are written in the standard notation also used by SGF's `RE` property:
+/// the winner's color, a plus sign, and either the margin (`W+3.5`) or a letter for
+/// the reason (`B+R` for resignation, `B+T` for time, `B+F` for forfeit). A jigo
+/// (drawn game) is written `0`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameResult {
+    /// The winning player, or `None` for a jigo
+    pub winner: Option<Occupant>,
+
+    /// Points the winner won by, for games decided by counting
+    pub margin: Option<f32>,
+
+    /// How the game was decided
+    pub reason: ResultReason,
+}
+
+impl GameResult {
+    /// Builds the result of a counted game from both players' final scores.
+    ///
+    /// # Arguments
+    /// * `black_score` - Black's final score
+    /// * `white_score` - White's final score, including komi
+    pub fn from_score(black_score: f32, white_score: f32) -> Self {
+        let margin = (black_score - white_score).abs();
+        let winner = if black_score > white_score {
+            Occupant::Black
+        } else if white_score > black_score {
+            Occupant::White
+        } else {
+            return GameResult::jigo();
+        };
+        GameResult {
+            winner: Some(winner),
+            margin: Some(margin),
+            reason: ResultReason::Score,
+        }
+    }
+
+    /// Builds the result of a counted game that ended with equal scores.
+    pub fn jigo() -> Self {
+        GameResult {
+            winner: None,
+            margin: Some(0.0),
+            reason: ResultReason::Jigo,
+        }
+    }
+
+    /// Builds the result of a game `winner` won without counting.
+    ///
+    /// # Arguments
+    /// * `winner` - The winning player
+    /// * `reason` - Resignation, timeout or forfeit
+    pub fn win_by(winner: Occupant, reason: ResultReason) -> Self {
+        GameResult {
+            winner: Some(winner),
+            margin: None,
+            reason,
+        }
+    }
+
+    /// Returns the result in standard notation, e.g. `"B+R"` or `"W+3.5"`.
+    pub fn to_notation(&self) -> String {
+        let color = match self.winner {
+            Some(Occupant::Black) => "B",
+            Some(Occupant::White) => "W",
+            _ => return "0".to_string(),
+        };
+        match self.reason {
+            ResultReason::Resignation => format!("{}+R", color),
+            ResultReason::Timeout => format!("{}+T", color),
+            ResultReason::Forfeit => format!("{}+F", color),
+            ResultReason::Score | ResultReason::Jigo => match self.margin {
+                Some(margin) => format!("{}+{}", color, margin),
+                None => format!("{}+", color),
+            },
+        }
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_notation())
+    }
+}
+
+impl FromStr for GameResult {
+    type Err = String;
+
+    /// Parses a result in standard notation.
+    ///
+    /// Accepts the long SGF forms (`B+Resign`, `W+Time`, `B+Forfeit`), `0` or `Draw`
+    /// for jigo, and `B+` for a win by an unknown margin.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid game result: {}", s);
+        let trimmed = s.trim();
+        if trimmed == "0" || trimmed.eq_ignore_ascii_case("draw") {
+            return Ok(GameResult::jigo());
+        }
+
+        let (color, detail) = trimmed.split_once('+').ok_or_else(invalid)?;
+        let winner = match color.to_ascii_uppercase().as_str() {
+            "B" => Occupant::Black,
+            "W" => Occupant::White,
+            _ => return Err(invalid()),
+        };
+        let result = match detail.to_ascii_uppercase().as_str() {
+            "R" | "RESIGN" => GameResult::win_by(winner, ResultReason::Resignation),
+            "T" | "TIME" => GameResult::win_by(winner, ResultReason::Timeout),
+            "F" | "FORFEIT" => GameResult::win_by(winner, ResultReason::Forfeit),
+            "" => GameResult {
+                winner: Some(winner),
+                margin: None,
+                reason: ResultReason::Score,
+            },
+            margin => GameResult {
+                winner: Some(winner),
+                margin: Some(margin.parse().map_err(|_| invalid())?),
+                reason: ResultReason::Score,
+            },
+        };
+        Ok(result)
+    }
+}
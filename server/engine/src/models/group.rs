@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use super::point::Point;
 use super::spot::Occupant;
 
 /// Represents a connected chain of stones of the same color in a Go game.
@@ -11,9 +12,9 @@ pub struct Group {
     /// The color of all stones in this group
     pub occupant: Occupant,
     
-    /// Coordinates of all stones in this group as (row, column) pairs
-    pub stones: Vec<(u8, u8)>,
+    /// Points of all stones in this group
+    pub stones: Vec<Point>,
     
-    /// Set of coordinates for all empty spots adjacent to this group
-    pub liberties: HashSet<(u8, u8)>,
+    /// Set of points for all empty spots adjacent to this group
+    pub liberties: HashSet<Point>,
 }
\ No newline at end of file
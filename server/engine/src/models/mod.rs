@@ -0,0 +1,32 @@
+/*!
+ * Core data models for the Go game.
+ *
+ * This module contains all the fundamental types and structures used
+ * to represent boards, stones, positions, and rulesets.
+ */
+
+pub mod bitboard;
+pub mod board;
+pub mod chains;
+pub mod clock;
+pub mod game_result;
+pub mod group;
+pub mod point;
+pub mod position;
+pub mod region;
+pub mod ruleset;
+pub mod spot;
+pub mod zobrist;
+
+pub use bitboard::*;
+pub use board::*;
+pub use chains::*;
+pub use clock::*;
+pub use game_result::*;
+pub use group::*;
+pub use point::*;
+pub use position::*;
+pub use region::*;
+pub use ruleset::*;
+pub use spot::*;
+pub use zobrist::*;
@@ -0,0 +1,187 @@
+use super::zobrist::MAX_BOARD_SIZE;
+
+/// Column letters used by GTP vertices, which skip "I" to avoid confusion with "J".
+const GTP_COLUMNS: &[u8; 25] = b"ABCDEFGHJKLMNOPQRSTUVWXYZ";
+
+/// An intersection on the board, addressed by zero-based row and column.
+///
+/// Row 0 is the top row and column 0 the leftmost column, matching the row-major
+/// order of `Board::spots`. Points order by row, then column, so sorting points
+/// gives the same order as sorting their flat indices.
+///
+/// A point does not know the size of its board: conversions that depend on it
+/// (flat indices, GTP vertices, bounds checks) take the board size as an argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    /// Zero-based row index, counted from the top
+    pub row: u8,
+
+    /// Zero-based column index, counted from the left
+    pub col: u8,
+}
+
+impl Point {
+    /// Creates a point without checking it against a board size.
+    pub const fn new(row: u8, col: u8) -> Self {
+        Point { row, col }
+    }
+
+    /// Creates a point, checking that it lies on a board of the given size.
+    ///
+    /// # Arguments
+    /// * `row` - Zero-based row index
+    /// * `col` - Zero-based column index
+    /// * `board_size` - Size of the board
+    ///
+    /// # Returns
+    /// * `Ok(Point)` - The point, if it is on the board
+    /// * `Err(message)` - If either coordinate is out of bounds
+    pub fn on_board(row: usize, col: usize, board_size: u8) -> Result<Self, String> {
+        let size = board_size as usize;
+        if row >= size || col >= size {
+            return Err(format!(
+                "Point (row {}, col {}) is outside a {}x{} board",
+                row, col, board_size, board_size
+            ));
+        }
+        Ok(Point::new(row as u8, col as u8))
+    }
+
+    /// Creates a point from its index in the row-major `spots` vector.
+    ///
+    /// # Returns
+    /// * `Ok(Point)` - The point at that index
+    /// * `Err(message)` - If the index is beyond the last point of the board
+    pub fn from_index(idx: usize, board_size: u8) -> Result<Self, String> {
+        let size = board_size as usize;
+        if idx >= size * size {
+            return Err(format!(
+                "Index {} is outside a {}x{} board",
+                idx, board_size, board_size
+            ));
+        }
+        Ok(Point::new((idx / size) as u8, (idx % size) as u8))
+    }
+
+    /// Returns the index of this point in the row-major `spots` vector.
+    pub fn index(&self, board_size: u8) -> usize {
+        (self.row as usize) * (board_size as usize) + (self.col as usize)
+    }
+
+    /// Returns whether this point lies on a board of the given size.
+    pub fn is_on_board(&self, board_size: u8) -> bool {
+        self.row < board_size && self.col < board_size
+    }
+
+    /// Returns the orthogonally adjacent points that lie on the board.
+    ///
+    /// In Go, only orthogonally adjacent positions (not diagonals) are considered connected.
+    pub fn neighbors(&self, board_size: u8) -> Vec<Point> {
+        let mut result = Vec::with_capacity(4); // At most 4 orthogonal neighbors
+
+        if self.row > 0 {
+            result.push(Point::new(self.row - 1, self.col)); // North
+        }
+        if self.row + 1 < board_size {
+            result.push(Point::new(self.row + 1, self.col)); // South
+        }
+        if self.col > 0 {
+            result.push(Point::new(self.row, self.col - 1)); // West
+        }
+        if self.col + 1 < board_size {
+            result.push(Point::new(self.row, self.col + 1)); // East
+        }
+
+        result
+    }
+
+    /// Parses a GTP vertex such as `"D4"` or `"q16"`.
+    ///
+    /// GTP columns are lettered from the left starting at "A" and skipping "I";
+    /// rows are numbered from 1 at the bottom of the board. Letters are case-insensitive.
+    ///
+    /// # Arguments
+    /// * `vertex` - The vertex to parse (not "pass" or "resign")
+    /// * `board_size` - Size of the board, needed because rows count from the bottom
+    ///
+    /// # Returns
+    /// * `Ok(Point)` - The point the vertex names
+    /// * `Err(message)` - If the vertex is malformed or off the board
+    pub fn from_gtp(vertex: &str, board_size: u8) -> Result<Self, String> {
+        let vertex = vertex.trim();
+        let invalid = || format!("Invalid GTP vertex: {}", vertex);
+
+        let mut chars = vertex.chars();
+        let letter = chars.next().ok_or_else(invalid)?.to_ascii_uppercase();
+        let col = GTP_COLUMNS
+            .iter()
+            .position(|&c| c as char == letter)
+            .ok_or_else(invalid)?;
+        let number: usize = chars.as_str().parse().map_err(|_| invalid())?;
+        if number == 0 || number > board_size as usize {
+            return Err(invalid());
+        }
+
+        Point::on_board(board_size as usize - number, col, board_size).map_err(|_| invalid())
+    }
+
+    /// Formats this point as a GTP vertex, e.g. `"D4"`.
+    ///
+    /// # Panics
+    /// Panics if the point is not on a board of the given size.
+    pub fn to_gtp(&self, board_size: u8) -> String {
+        assert!(
+            self.is_on_board(board_size),
+            "{:?} is outside a {}x{} board",
+            self,
+            board_size,
+            board_size
+        );
+        format!(
+            "{}{}",
+            GTP_COLUMNS[self.col as usize] as char,
+            board_size - self.row
+        )
+    }
+
+    /// Parses an SGF point such as `"dd"`.
+    ///
+    /// SGF points are two lowercase letters, column first, with "a" for the leftmost
+    /// column and the top row. The empty pass value (`""`) is not a point.
+    ///
+    /// # Arguments
+    /// * `coord` - The two-letter coordinate
+    /// * `board_size` - Size of the board, for bounds checking
+    ///
+    /// # Returns
+    /// * `Ok(Point)` - The point the coordinate names
+    /// * `Err(message)` - If the coordinate is malformed or off the board
+    pub fn from_sgf(coord: &str, board_size: u8) -> Result<Self, String> {
+        let invalid = || format!("Invalid SGF point: {}", coord);
+        let [col, row] = coord.as_bytes() else {
+            return Err(invalid());
+        };
+        if !col.is_ascii_lowercase() || !row.is_ascii_lowercase() {
+            return Err(invalid());
+        }
+
+        Point::on_board((row - b'a') as usize, (col - b'a') as usize, board_size)
+            .map_err(|_| invalid())
+    }
+
+    /// Formats this point as an SGF point, e.g. `"dd"`.
+    ///
+    /// # Panics
+    /// Panics if the point is beyond the largest supported board.
+    pub fn to_sgf(&self) -> String {
+        assert!(
+            self.is_on_board(MAX_BOARD_SIZE),
+            "{:?} is outside the largest supported board",
+            self
+        );
+        [b'a' + self.col, b'a' + self.row]
+            .iter()
+            .map(|&c| c as char)
+            .collect()
+    }
+}
@@ -0,0 +1,184 @@
+use super::bitboard::{Bitboard, Geometry};
+use super::board::Board;
+use super::spot::Occupant;
+use super::zobrist::{size_key, stone_key};
+
+/// The compact core representation of a board position used by the rules engine.
+///
+/// A position stores only which points hold black and white stones, as bitboards,
+/// together with its Zobrist hash. It is `Copy` and carries none of the presentation
+/// data in [`crate::models::SpotState`], so engine code can copy and simulate
+/// positions cheaply; `SpotState`s are only produced when results are written back
+/// to a [`Board`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    /// Shift masks for the board size
+    geometry: Geometry,
+
+    /// Points holding black stones
+    black: Bitboard,
+
+    /// Points holding white stones
+    white: Bitboard,
+
+    /// Zobrist hash of the stone placement
+    hash: u64,
+}
+
+impl Position {
+    /// Creates an empty position of the given size.
+    pub fn new(board_size: u8) -> Self {
+        Position {
+            geometry: Geometry::new(board_size),
+            black: Bitboard::EMPTY,
+            white: Bitboard::EMPTY,
+            hash: size_key(board_size),
+        }
+    }
+
+    /// Creates a position from a slice of occupants in row-major order.
+    ///
+    /// # Panics
+    /// Panics if the number of occupants does not equal board_size².
+    pub fn from_occupants(occupants: &[Occupant], board_size: u8) -> Self {
+        assert_eq!(
+            occupants.len(),
+            (board_size as usize).pow(2),
+            "Invalid position: contains {} points but expected {} for size {}",
+            occupants.len(),
+            (board_size as usize).pow(2),
+            board_size
+        );
+        let mut position = Position::new(board_size);
+        for (idx, occupant) in occupants.iter().enumerate() {
+            position.set(idx, occupant);
+        }
+        position
+    }
+
+    /// Creates a position holding the stones currently on a board.
+    pub fn from_board(board: &Board) -> Self {
+        let mut position = Position::new(board.board_size);
+        for (idx, spot) in board.spots.iter().enumerate() {
+            position.set(idx, &spot.occupant);
+        }
+        position
+    }
+
+    /// Returns the size of the board.
+    pub fn board_size(&self) -> u8 {
+        self.geometry.board_size()
+    }
+
+    /// Returns the shift masks for the board size.
+    pub fn geometry(&self) -> &Geometry {
+        &self.geometry
+    }
+
+    /// Returns the Zobrist hash of the stone placement.
+    ///
+    /// This is the same value [`Board::position_hash`] reports for an identical board.
+    pub fn position_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the points holding stones of `color` (or the empty points for `Occupant::Empty`).
+    pub fn stones(&self, color: &Occupant) -> Bitboard {
+        match color {
+            Occupant::Black => self.black,
+            Occupant::White => self.white,
+            Occupant::Empty => self.empty(),
+        }
+    }
+
+    /// Returns the empty points.
+    pub fn empty(&self) -> Bitboard {
+        self.geometry.all() & !(self.black | self.white)
+    }
+
+    /// Returns what occupies the point at `idx`.
+    pub fn occupant(&self, idx: usize) -> Occupant {
+        if self.black.contains(idx) {
+            Occupant::Black
+        } else if self.white.contains(idx) {
+            Occupant::White
+        } else {
+            Occupant::Empty
+        }
+    }
+
+    /// Returns the occupants of every point in row-major order.
+    pub fn occupants(&self) -> Vec<Occupant> {
+        let points = (self.board_size() as usize).pow(2);
+        (0..points).map(|idx| self.occupant(idx)).collect()
+    }
+
+    /// Sets what occupies the point at `idx`, keeping the hash up to date.
+    pub fn set(&mut self, idx: usize, occupant: &Occupant) {
+        self.hash ^= self.key(idx, &self.occupant(idx));
+        self.black.remove(idx);
+        self.white.remove(idx);
+        match occupant {
+            Occupant::Black => self.black.insert(idx),
+            Occupant::White => self.white.insert(idx),
+            Occupant::Empty => {}
+        }
+        self.hash ^= self.key(idx, occupant);
+    }
+
+    /// Removes every stone in `stones` from the board, keeping the hash up to date.
+    pub fn remove(&mut self, stones: Bitboard) {
+        for idx in stones.iter() {
+            self.hash ^= self.key(idx, &self.occupant(idx));
+        }
+        self.black &= !stones;
+        self.white &= !stones;
+    }
+
+    /// Returns the chain (group) containing the stone at `idx`, or an empty bitboard
+    /// if the point is empty.
+    pub fn chain_at(&self, idx: usize) -> Bitboard {
+        let color = self.occupant(idx);
+        if color == Occupant::Empty {
+            return Bitboard::EMPTY;
+        }
+        self.geometry
+            .flood_fill(Bitboard::single(idx), self.stones(&color))
+    }
+
+    /// Returns the liberties (adjacent empty points) of a set of stones.
+    pub fn liberties(&self, stones: Bitboard) -> Bitboard {
+        self.geometry.adjacent(stones) & self.empty()
+    }
+
+    /// Returns every chain on the board with its color, ordered by lowest point.
+    pub fn chains(&self) -> Vec<(Occupant, Bitboard)> {
+        let mut chains = Vec::new();
+        let mut remaining = self.black | self.white;
+        while let Some(idx) = remaining.first() {
+            let chain = self.chain_at(idx);
+            chains.push((self.occupant(idx), chain));
+            remaining &= !chain;
+        }
+        chains
+    }
+
+    /// Returns every connected region of empty points, ordered by lowest point.
+    pub fn empty_regions(&self) -> Vec<Bitboard> {
+        let empty = self.empty();
+        let mut regions = Vec::new();
+        let mut remaining = empty;
+        while let Some(idx) = remaining.first() {
+            let region = self.geometry.flood_fill(Bitboard::single(idx), empty);
+            regions.push(region);
+            remaining &= !region;
+        }
+        regions
+    }
+
+    /// Returns the Zobrist key of a stone of `color` at `idx`.
+    fn key(&self, idx: usize, color: &Occupant) -> u64 {
+        let size = self.board_size() as usize;
+        stone_key((idx / size) as u8, (idx % size) as u8, color)
+    }
+}
@@ -1,3 +1,4 @@
+use super::point::Point;
 use super::spot::Occupant;
 use std::collections::HashSet;
 
@@ -8,14 +9,14 @@ use std::collections::HashSet;
 /// belonging to one player depending on its border properties.
 #[derive(Debug, Clone)]
 pub struct EmptyRegion {
-    /// Coordinates of all empty spots in this region as (row, column) pairs
-    pub spots: Vec<(u8, u8)>,
+    /// Points of all empty spots in this region
+    pub spots: Vec<Point>,
 
     /// Set of stone colors that border this empty region
     /// If this set contains only one color, the region is territory for that player
     pub border: HashSet<Occupant>,
 
     /// Whether this region touches the edge of the board
-    /// In traditional scoring, regions touching the edge are not counted as territory
+    /// This does not affect scoring: the edge encloses territory just like stones do
     pub touches_edge: bool,
 }
@@ -0,0 +1,274 @@
+use super::spot::Occupant;
+use super::zobrist::position_key;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The set of Go rules a game is played under.
+///
+/// A ruleset decides which ko variant is enforced, how the final position is
+/// scored, the default komi, and how White is compensated for handicap stones.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Ruleset {
+    /// Chinese rules: area scoring with positional superko
+    #[default]
+    Chinese,
+    /// Japanese rules: territory scoring with the basic ko rule
+    Japanese,
+    /// Korean rules: territory scoring with the basic ko rule
+    Korean,
+    /// American Go Association rules: area scoring with situational superko
+    Aga,
+    /// New Zealand rules: area scoring with situational superko
+    NewZealand,
+    /// Tromp-Taylor rules: the mechanical reference ruleset with positional superko
+    TrompTaylor,
+    /// Ing (SST) rules: area scoring with superko
+    Ing,
+}
+
+/// Which repetitions of earlier positions a ruleset forbids.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KoRule {
+    /// Only immediate recapture of a ko is forbidden
+    Simple,
+    /// No move may recreate any earlier board position
+    PositionalSuperko,
+    /// No move may recreate an earlier board position with the same player to move
+    SituationalSuperko,
+}
+
+/// Defines different methods for scoring a Go game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoringMethod {
+    /// Area scoring: stones on board + enclosed territory
+    Area,
+    /// Territory scoring: enclosed territory only (plus captured stones)
+    Territory,
+    /// Tromp-Taylor scoring: stones plus empty points that reach only that color,
+    /// counted mechanically with every stone on the board treated as alive
+    TrompTaylor,
+}
+
+impl Ruleset {
+    /// All supported rulesets, in display order.
+    pub const ALL: [Ruleset; 7] = [
+        Ruleset::Chinese,
+        Ruleset::Japanese,
+        Ruleset::Korean,
+        Ruleset::Aga,
+        Ruleset::NewZealand,
+        Ruleset::TrompTaylor,
+        Ruleset::Ing,
+    ];
+
+    /// Returns the identifier used to store this ruleset on a game.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Ruleset::Chinese => "chinese",
+            Ruleset::Japanese => "japanese",
+            Ruleset::Korean => "korean",
+            Ruleset::Aga => "aga",
+            Ruleset::NewZealand => "new_zealand",
+            Ruleset::TrompTaylor => "tromp_taylor",
+            Ruleset::Ing => "ing",
+        }
+    }
+
+    /// Returns the name of this ruleset as written in SGF's `RU` property.
+    pub fn sgf_name(&self) -> &'static str {
+        match self {
+            Ruleset::Chinese => "Chinese",
+            Ruleset::Japanese => "Japanese",
+            Ruleset::Korean => "Korean",
+            Ruleset::Aga => "AGA",
+            Ruleset::NewZealand => "NZ",
+            Ruleset::TrompTaylor => "Tromp-Taylor",
+            Ruleset::Ing => "GOE",
+        }
+    }
+
+    /// Looks up a ruleset by the name written in SGF's `RU` property.
+    ///
+    /// Names are matched without regard to case, and common alternatives such as
+    /// "New Zealand" and "Ing" are accepted.
+    ///
+    /// # Returns
+    /// The ruleset, or None if the name is not recognised
+    pub fn from_sgf_name(name: &str) -> Option<Ruleset> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "chinese" | "cn" => Some(Ruleset::Chinese),
+            "japanese" | "jp" => Some(Ruleset::Japanese),
+            "korean" | "kr" => Some(Ruleset::Korean),
+            "aga" => Some(Ruleset::Aga),
+            "nz" | "new zealand" => Some(Ruleset::NewZealand),
+            "tromp-taylor" | "tromp taylor" => Some(Ruleset::TrompTaylor),
+            "goe" | "ing" => Some(Ruleset::Ing),
+            _ => None,
+        }
+    }
+
+    /// Returns the ko variant enforced under this ruleset.
+    pub fn ko_rule(&self) -> KoRule {
+        match self {
+            Ruleset::Japanese | Ruleset::Korean => KoRule::Simple,
+            Ruleset::Aga | Ruleset::NewZealand => KoRule::SituationalSuperko,
+            Ruleset::Chinese | Ruleset::TrompTaylor | Ruleset::Ing => KoRule::PositionalSuperko,
+        }
+    }
+
+    /// Checks whether a move producing `new_board_hash` repeats an earlier position in a
+    /// way this ruleset's ko rule forbids.
+    ///
+    /// # Arguments
+    /// * `position_history` - Position keys (see [`position_key`]) of every earlier
+    ///   position in the game, ending with the current one
+    /// * `new_board_hash` - Zobrist hash of the board the move would produce
+    /// * `next_to_move` - The player to move after the move (the mover's opponent)
+    ///
+    /// # Returns
+    /// * `Ok(())` - The move does not violate the ko rule
+    /// * `Err(message)` - An error naming the violated rule
+    pub fn check_repetition(
+        &self,
+        position_history: &[u64],
+        new_board_hash: u64,
+        next_to_move: &Occupant,
+    ) -> Result<(), String> {
+        let new_key = position_key(new_board_hash, next_to_move);
+        match self.ko_rule() {
+            KoRule::Simple => {
+                // Only the position before the opponent's last move is off limits
+                let before_last_move = position_history.len().checked_sub(2);
+                if before_last_move.is_some_and(|i| position_history[i] == new_key) {
+                    return Err("Illegal move: violates ko rule (simple ko)".to_string());
+                }
+            }
+            KoRule::PositionalSuperko => {
+                // The board may not repeat, whoever is to move
+                let repeated = position_history
+                    .contains(&position_key(new_board_hash, &Occupant::Black))
+                    || position_history.contains(&position_key(new_board_hash, &Occupant::White));
+                if repeated {
+                    return Err("Illegal move: violates ko rule (positional superko)".to_string());
+                }
+            }
+            KoRule::SituationalSuperko => {
+                // The board may not repeat with the same player to move
+                if position_history.contains(&new_key) {
+                    return Err("Illegal move: violates ko rule (situational superko)".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether a player may play a stone that leaves its own group without
+    /// liberties, removing that group from the board.
+    ///
+    /// New Zealand and Tromp-Taylor rules allow (multi-stone) suicide; all others forbid it.
+    pub fn allows_suicide(&self) -> bool {
+        matches!(self, Ruleset::NewZealand | Ruleset::TrompTaylor)
+    }
+
+    /// Returns the scoring method used to count the final position.
+    pub fn scoring_method(&self) -> ScoringMethod {
+        match self {
+            Ruleset::Japanese | Ruleset::Korean => ScoringMethod::Territory,
+            Ruleset::TrompTaylor => ScoringMethod::TrompTaylor,
+            _ => ScoringMethod::Area,
+        }
+    }
+
+    /// Returns the komi White receives by default.
+    ///
+    /// Handicap games get a nominal half point so that they cannot end in a tie. On
+    /// boards of 9×9 and smaller, area rulesets use a komi of 7, as is usual for small
+    /// boards; larger boards use the ruleset's standard komi.
+    ///
+    /// # Arguments
+    /// * `handicap` - Number of handicap stones Black received
+    /// * `board_size` - Size of the board
+    pub fn default_komi(&self, handicap: u8, board_size: u8) -> f32 {
+        if handicap > 0 {
+            return 0.5;
+        }
+        if board_size <= 9 && self.scoring_method() != ScoringMethod::Territory {
+            return 7.0;
+        }
+        match self {
+            Ruleset::Japanese | Ruleset::Korean => 6.5,
+            Ruleset::NewZealand => 7.0,
+            Ruleset::Chinese | Ruleset::Aga | Ruleset::TrompTaylor | Ruleset::Ing => 7.5,
+        }
+    }
+
+    /// Returns the extra points White receives for Black's handicap stones.
+    ///
+    /// Under area scoring every handicap stone also counts for Black at the end of
+    /// the game, so most area rulesets give White one point per stone. AGA rules
+    /// give one point per stone after the first, and territory rulesets give none.
+    ///
+    /// # Arguments
+    /// * `handicap` - Number of handicap stones Black received
+    pub fn handicap_compensation(&self, handicap: u8) -> f32 {
+        if handicap < 2 {
+            return 0.0;
+        }
+        match self {
+            Ruleset::Japanese | Ruleset::Korean => 0.0,
+            Ruleset::Aga => (handicap - 1) as f32,
+            Ruleset::Chinese | Ruleset::NewZealand | Ruleset::TrompTaylor | Ruleset::Ing => {
+                handicap as f32
+            }
+        }
+    }
+}
+
+/// Checks that a komi can be used on a board of the given size.
+///
+/// Komi may be a whole or half number, and may be zero or negative (reverse komi,
+/// given to Black), but cannot exceed the number of points on the board.
+///
+/// # Arguments
+/// * `komi` - The komi to check
+/// * `board_size` - Size of the board
+///
+/// # Returns
+/// * `Ok(())` - The komi can be used
+/// * `Err(message)` - If the komi is not a multiple of 0.5 or is out of range
+pub fn validate_komi(komi: f32, board_size: u8) -> Result<(), String> {
+    if !komi.is_finite() || (komi * 2.0).fract() != 0.0 {
+        return Err(format!("Komi must be a multiple of 0.5, got {}", komi));
+    }
+    let points = (board_size as f32).powi(2);
+    if komi.abs() > points {
+        return Err(format!(
+            "Komi {} is larger than the {} points on a {}x{} board",
+            komi, points, board_size, board_size
+        ));
+    }
+    Ok(())
+}
+
+impl fmt::Display for Ruleset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Ruleset {
+    type Err = String;
+
+    /// Parses a ruleset identifier such as `"japanese"` or `"new_zealand"`.
+    ///
+    /// Matching is case-insensitive and accepts `-` or spaces in place of `_`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_ascii_lowercase().replace(['-', ' '], "_");
+        Ruleset::ALL
+            .iter()
+            .copied()
+            .find(|ruleset| ruleset.as_str() == normalized)
+            .ok_or_else(|| format!("Unknown ruleset: {}", s))
+    }
+}
@@ -31,4 +31,17 @@ pub struct SpotState {
 
     /// Human-readable explanation for why this spot was scored as it was
     pub scoring_explanation: Option<String>,
+
+    /// Whether the stone here is known to be alive or dead, if it can be decided
+    #[serde(default)]
+    pub group_status: Option<GroupStatus>,
+}
+
+/// The life-and-death status of a stone, as far as it can be decided without reading.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GroupStatus {
+    /// Part of a chain that can never be captured (pass-alive)
+    Alive,
+    /// Inside a region made vital by the opponent's pass-alive chains
+    Dead,
 }
@@ -0,0 +1,131 @@
+use super::spot::{Occupant, SpotState};
+
+/// Largest board size supported by the Zobrist key table.
+pub const MAX_BOARD_SIZE: u8 = 25;
+
+/// Number of points on the largest supported board.
+const MAX_POINTS: usize = (MAX_BOARD_SIZE as usize) * (MAX_BOARD_SIZE as usize);
+
+/// Fixed seed so that hashes are stable across builds and can be persisted.
+const SEED: u64 = 0x476f_5469_6d65_2121;
+
+/// Advances a SplitMix64 generator and returns the next pseudo-random value.
+const fn splitmix64(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (state, z ^ (z >> 31))
+}
+
+/// Random keys for a black and a white stone on every point of the largest board,
+/// followed by one key per board size and the key for White being to move.
+const KEYS: ([[u64; 2]; MAX_POINTS], [u64; MAX_BOARD_SIZE as usize + 1], u64) = {
+    let mut stones = [[0u64; 2]; MAX_POINTS];
+    let mut sizes = [0u64; MAX_BOARD_SIZE as usize + 1];
+    let mut state = SEED;
+    let mut i = 0;
+    while i < MAX_POINTS {
+        let (next, black) = splitmix64(state);
+        let (next, white) = splitmix64(next);
+        stones[i] = [black, white];
+        state = next;
+        i += 1;
+    }
+    let mut s = 0;
+    while s <= MAX_BOARD_SIZE as usize {
+        let (next, key) = splitmix64(state);
+        sizes[s] = key;
+        state = next;
+        s += 1;
+    }
+    let (_, white_to_move) = splitmix64(state);
+    (stones, sizes, white_to_move)
+};
+
+/// Returns the Zobrist key for a stone of the given color at (row, col).
+///
+/// Empty points have no key (0), so toggling an empty occupant is a no-op.
+///
+/// # Arguments
+/// * `row` - Zero-based row index
+/// * `col` - Zero-based column index
+/// * `occupant` - The stone color on that point
+pub fn stone_key(row: u8, col: u8, occupant: &Occupant) -> u64 {
+    let point = (row as usize) * (MAX_BOARD_SIZE as usize) + (col as usize);
+    match occupant {
+        Occupant::Black => KEYS.0[point][0],
+        Occupant::White => KEYS.0[point][1],
+        Occupant::Empty => 0,
+    }
+}
+
+/// Returns the key mixed into every hash for a board of the given size.
+///
+/// This keeps an empty 9×9 board from colliding with an empty 19×19 board when
+/// positions from different games are compared.
+pub fn size_key(board_size: u8) -> u64 {
+    KEYS.1[board_size as usize]
+}
+
+/// Checks that a board of the given size can be played on.
+///
+/// # Arguments
+/// * `board_size` - Size of the board
+///
+/// # Returns
+/// * `Ok(())` - The size is supported
+/// * `Err(message)` - If the size is zero or larger than [`MAX_BOARD_SIZE`]
+pub fn validate_board_size(board_size: u8) -> Result<(), String> {
+    if !(1..=MAX_BOARD_SIZE).contains(&board_size) {
+        return Err(format!(
+            "Board size must be between 1 and {}, not {}",
+            MAX_BOARD_SIZE, board_size
+        ));
+    }
+    Ok(())
+}
+
+/// Computes the Zobrist hash of a board from scratch.
+///
+/// Only stone placement contributes to the hash; UI metadata such as markers and
+/// scoring annotations is ignored.
+///
+/// # Arguments
+/// * `spots` - The board's spots in row-major order
+/// * `board_size` - Size of the board
+///
+/// # Panics
+/// Panics if `board_size` exceeds [`MAX_BOARD_SIZE`].
+pub fn hash_spots(spots: &[SpotState], board_size: u8) -> u64 {
+    assert!(
+        board_size <= MAX_BOARD_SIZE,
+        "Board size {} exceeds the maximum of {}",
+        board_size,
+        MAX_BOARD_SIZE
+    );
+
+    let size = board_size as usize;
+    let mut hash = size_key(board_size);
+    for (idx, spot) in spots.iter().enumerate() {
+        hash ^= stone_key((idx / size) as u8, (idx % size) as u8, &spot.occupant);
+    }
+    hash
+}
+
+/// Combines a board hash with the player to move into a single position key.
+///
+/// Position keys are what a game's position history stores. Keys of the same board
+/// with different players to move differ, which is what situational superko needs,
+/// while positional superko can still recover the board-only comparison because
+/// Black to move contributes nothing to the key.
+///
+/// # Arguments
+/// * `board_hash` - Zobrist hash of the stone placement
+/// * `to_move` - The player whose turn it is in this position
+pub fn position_key(board_hash: u64, to_move: &Occupant) -> u64 {
+    match to_move {
+        Occupant::White => board_hash ^ KEYS.2,
+        _ => board_hash,
+    }
+}
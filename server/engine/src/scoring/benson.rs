@@ -0,0 +1,123 @@
+use crate::models::{Bitboard, Board, Group, Occupant, Point, Position};
+
+/// The chains of one color that can never be captured, and the regions they make vital.
+///
+/// Found by [`find_pass_alive`] using Benson's algorithm.
+#[derive(Debug, Clone)]
+pub struct PassAlive {
+    /// The color of the chains
+    pub color: Occupant,
+
+    /// The unconditionally alive (pass-alive) chains
+    pub chains: Vec<Group>,
+
+    /// Regions enclosed by the alive chains in which every empty point is a liberty of
+    /// one of them. Each region holds its empty points and any opponent stones in it;
+    /// those stones can never live
+    pub vital_regions: Vec<Vec<Point>>,
+}
+
+/// Finds the chains of `color` that are unconditionally alive using Benson's algorithm.
+///
+/// A chain is unconditionally (or "pass-") alive if the opponent cannot capture it even
+/// when its owner passes every turn. Benson's algorithm finds these chains without any
+/// reading:
+///
+/// 1. Split the board into the chains of `color` and the regions between them, where a
+///    region is a maximal connected set of points not occupied by `color`.
+/// 2. A region is vital to a chain if every empty point of the region is a liberty of
+///    that chain.
+/// 3. Discard every chain that has fewer than two vital regions, then every region that
+///    borders a discarded chain, and repeat until nothing changes.
+///
+/// The chains that remain are pass-alive.
+///
+/// # Arguments
+/// * `board` - The board to analyze
+/// * `color` - The color whose chains to examine
+///
+/// # Returns
+/// The pass-alive chains of `color` and the regions they make vital
+pub fn find_pass_alive(board: &Board, color: &Occupant) -> PassAlive {
+    let position = Position::from_board(board);
+    let geometry = position.geometry();
+    let size = board.board_size;
+    let own = position.stones(color);
+    let empty = position.empty();
+
+    let mut chains: Vec<Bitboard> = position
+        .chains()
+        .into_iter()
+        .filter(|(occupant, _)| occupant == color)
+        .map(|(_, chain)| chain)
+        .collect();
+
+    let not_own = geometry.all() & !own;
+    let mut regions = Vec::new();
+    let mut remaining = not_own;
+    while let Some(idx) = remaining.first() {
+        let region = geometry.flood_fill(Bitboard::single(idx), not_own);
+        regions.push(region);
+        remaining &= !region;
+    }
+
+    let is_vital = |region: Bitboard, chain: Bitboard| {
+        let adjacent = geometry.adjacent(chain);
+        !(adjacent & region).is_empty() && (region & empty & !adjacent).is_empty()
+    };
+
+    loop {
+        let (chain_count, region_count) = (chains.len(), regions.len());
+
+        chains.retain(|&chain| regions.iter().filter(|&&r| is_vital(r, chain)).count() >= 2);
+
+        let alive = chains.iter().fold(Bitboard::EMPTY, |all, &chain| all | chain);
+        regions.retain(|&region| (geometry.adjacent(region) & own & !alive).is_empty());
+
+        if chains.len() == chain_count && regions.len() == region_count {
+            break;
+        }
+    }
+
+    let vital_regions = regions
+        .into_iter()
+        .filter(|&region| chains.iter().any(|&chain| is_vital(region, chain)))
+        .map(|region| region.points(size).collect())
+        .collect();
+    let chains = chains
+        .into_iter()
+        .map(|chain| Group {
+            occupant: color.clone(),
+            stones: chain.points(size).collect(),
+            liberties: position.liberties(chain).points(size).collect(),
+        })
+        .collect();
+
+    PassAlive {
+        color: color.clone(),
+        chains,
+        vital_regions,
+    }
+}
+
+/// Finds the stones of both colors that are unconditionally dead.
+///
+/// A stone is unconditionally dead if it lies in a region made vital by the opponent's
+/// pass-alive chains (see [`find_pass_alive`]): it can never form an eye there, and
+/// the surrounding chains can never be captured.
+///
+/// # Arguments
+/// * `board` - The board to analyze
+///
+/// # Returns
+/// The dead stones, in row-major order
+pub fn find_dead_stones(board: &Board) -> Vec<Point> {
+    let mut dead: Vec<Point> = [Occupant::Black, Occupant::White]
+        .iter()
+        .flat_map(|color| find_pass_alive(board, color).vital_regions)
+        .flatten()
+        .filter(|&point| board.get(point).is_some_and(|spot| spot.occupant != Occupant::Empty))
+        .collect();
+    dead.sort();
+    dead
+}
@@ -0,0 +1,95 @@
+use crate::models::{Board, Group, Occupant, Point};
+use super::benson::find_dead_stones;
+use super::groups::find_groups;
+
+/// Marks the group at `point` dead, or alive again if it is already marked dead.
+///
+/// During the scoring phase players agree on which stones are dead by toggling whole
+/// groups, so marking any stone of a group marks all of its stones.
+///
+/// # Arguments
+/// * `board` - The final board position
+/// * `dead_stones` - The stones currently marked dead
+/// * `point` - A stone of the group to toggle
+///
+/// # Returns
+/// * `Ok(Vec<Point>)` - The updated dead stones, in row-major order
+/// * `Err(message)` - If there is no stone at `point`
+pub fn toggle_dead_group(
+    board: &Board,
+    dead_stones: &[Point],
+    point: Point,
+) -> Result<Vec<Point>, String> {
+    let group = find_groups(board)
+        .into_iter()
+        .find(|group| group.stones.contains(&point))
+        .ok_or_else(|| format!("There is no stone at (row {}, col {})", point.row, point.col))?;
+
+    let mut dead: Vec<Point> = if dead_stones.contains(&point) {
+        dead_stones
+            .iter()
+            .filter(|stone| !group.stones.contains(stone))
+            .copied()
+            .collect()
+    } else {
+        dead_stones.iter().chain(&group.stones).copied().collect()
+    };
+    dead.sort();
+    dead.dedup();
+    Ok(dead)
+}
+
+/// Takes the stones marked dead off the board, marking their spots as "dead".
+///
+/// # Arguments
+/// * `board` - The board to modify
+/// * `dead_stones` - The stones agreed to be dead
+///
+/// # Returns
+/// A tuple (taken by Black, taken by White) of the prisoners the removal adds: dead
+/// White stones are Black's prisoners and vice versa
+pub fn remove_marked_stones(board: &mut Board, dead_stones: &[Point]) -> (u32, u32) {
+    let mut black_prisoners = 0;
+    let mut white_prisoners = 0;
+    for &point in dead_stones {
+        match board.get(point).map(|spot| spot.occupant.clone()) {
+            Some(Occupant::White) => black_prisoners += 1,
+            Some(Occupant::Black) => white_prisoners += 1,
+            _ => continue,
+        }
+        board.set_occupant(point, Occupant::Empty);
+        if let Some(spot) = board.get_mut(point) {
+            spot.move_number = None;
+            spot.marker = Some("dead".to_string());
+        }
+    }
+    (black_prisoners, white_prisoners)
+}
+
+/// Takes the unconditionally dead stones (see [`find_dead_stones`]) off the board,
+/// marking their spots as "removed".
+///
+/// # Arguments
+/// * `board` - The game board to modify
+///
+/// # Returns
+/// The removed groups, e.g. to count as prisoners with
+/// [`count_prisoners`](super::groups::count_prisoners)
+pub fn remove_dead_stones(board: &mut Board) -> Vec<Group> {
+    let dead = find_dead_stones(board);
+    let dead_groups: Vec<Group> = find_groups(board)
+        .into_iter()
+        .filter(|group| group.stones.iter().any(|stone| dead.contains(stone)))
+        .collect();
+
+    for group in &dead_groups {
+        for &point in &group.stones {
+            board.set_occupant(point, Occupant::Empty);
+            if let Some(spot) = board.get_mut(point) {
+                spot.move_number = None;
+                spot.marker = Some("removed".to_string());
+            }
+        }
+    }
+    dead_groups
+}
@@ -0,0 +1,49 @@
+use crate::models::{Board, Group, Occupant, Position};
+
+/// Finds all stone groups (connected components of the same color) on the board.
+///
+/// The search runs on the board's bitboard [`Position`]: each group is found with a
+/// shift-based flood fill over the stones of its color, and its liberties are the
+/// empty points adjacent to the group. Groups are returned in order of their first
+/// stone in row-major order.
+///
+/// # Arguments
+/// * `board` - The game board to analyze
+///
+/// # Returns
+/// A vector of Group objects, each representing a connected set of stones
+pub fn find_groups(board: &Board) -> Vec<Group> {
+    let position = Position::from_board(board);
+    let size = board.board_size;
+
+    position
+        .chains()
+        .into_iter()
+        .map(|(occupant, stones)| Group {
+            occupant,
+            stones: stones.points(size).collect(),
+            liberties: position.liberties(stones).points(size).collect(),
+        })
+        .collect()
+}
+
+/// Counts the stones of removed groups as prisoners for the player who took them.
+///
+/// # Arguments
+/// * `groups` - Groups taken off the board, e.g. by [`remove_dead_stones`](super::dead_stones::remove_dead_stones)
+///
+/// # Returns
+/// A tuple (taken by Black, taken by White): removed White stones are Black's
+/// prisoners and vice versa
+pub fn count_prisoners(groups: &[Group]) -> (u32, u32) {
+    let mut black_prisoners = 0;
+    let mut white_prisoners = 0;
+    for group in groups {
+        match group.occupant {
+            Occupant::White => black_prisoners += group.stones.len() as u32,
+            Occupant::Black => white_prisoners += group.stones.len() as u32,
+            Occupant::Empty => {}
+        }
+    }
+    (black_prisoners, white_prisoners)
+}
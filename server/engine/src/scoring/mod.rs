@@ -0,0 +1,25 @@
+/*!
+ * Scoring and board analysis for the Go game.
+ *
+ * This module contains functionality for analyzing the game board, including:
+ * - Group detection and liberty counting
+ * - Territory determination 
+ * - Scoring calculations (both area and territory scoring methods)
+ * - Dead stone removal and marking
+ * - Benson's algorithm for unconditionally alive groups
+ * - Seki detection
+ */
+
+pub mod benson;
+pub mod dead_stones;
+pub mod groups;
+pub mod score;
+pub mod seki;
+pub mod territory;
+
+pub use benson::*;
+pub use dead_stones::*;
+pub use groups::*;
+pub use score::*;
+pub use seki::*;
+pub use territory::*;
\ No newline at end of file
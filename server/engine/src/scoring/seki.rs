@@ -0,0 +1,125 @@
+use crate::models::{Bitboard, Board, EmptyRegion, Group, Occupant, Point, Position};
+use super::groups::find_groups;
+use super::territory::find_empty_regions;
+
+/// Largest region that can be the eye of a group in seki. A chain in seki has hardly
+/// any liberties besides its shared ones, so its eyes are a point or two.
+const MAX_EYE_SIZE: usize = 2;
+
+/// Groups in seki (mutual life) and the empty points that belong to them.
+///
+/// Found by [`find_seki`].
+#[derive(Debug, Clone, Default)]
+pub struct Seki {
+    /// The chains in seki, of both colors
+    pub chains: Vec<Group>,
+
+    /// Liberties shared by chains of both colors, which neither player can fill
+    /// without putting their own chain in atari
+    pub shared_liberties: Vec<Point>,
+
+    /// Eyes of the chains in seki: small empty regions enclosed by a single chain in
+    /// seki and the edge of the board
+    pub eyes: Vec<EmptyRegion>,
+}
+
+impl Seki {
+    /// Returns whether the point is a shared liberty or eye of a group in seki.
+    pub fn contains(&self, point: Point) -> bool {
+        self.shared_liberties.contains(&point)
+            || self.eyes.iter().any(|eye| eye.spots.contains(&point))
+    }
+}
+
+/// Finds groups in seki on a board whose dead stones have been removed.
+///
+/// An empty point is a shared liberty of a seki if it is adjacent to stones of both
+/// colors and a stone played there by either player would be left with at most one
+/// liberty without capturing anything, so that neither player can approach. The chains
+/// adjacent to such points are in seki, and the small regions enclosed by one of
+/// those chains alone are their eyes. Larger regions, and regions that other stones
+/// help enclose, are territory as usual.
+///
+/// This recognises the common seki shapes (two groups sharing two liberties, or each
+/// with one eye and one shared liberty) without reading; capturing races where one
+/// side can safely fill a shared liberty are not seki.
+///
+/// # Arguments
+/// * `board` - The board to analyze, with dead stones already removed
+///
+/// # Returns
+/// The chains in seki, their shared liberties and their eyes
+pub fn find_seki(board: &Board) -> Seki {
+    let position = Position::from_board(board);
+    let geometry = position.geometry();
+    let size = board.board_size;
+    let black = position.stones(&Occupant::Black);
+    let white = position.stones(&Occupant::White);
+
+    let mut shared = Bitboard::EMPTY;
+    for idx in position.empty().iter() {
+        let adjacent = geometry.adjacent(Bitboard::single(idx));
+        if (adjacent & black).is_empty() || (adjacent & white).is_empty() {
+            continue;
+        }
+        if is_self_atari(&position, idx, &Occupant::Black)
+            && is_self_atari(&position, idx, &Occupant::White)
+        {
+            shared.insert(idx);
+        }
+    }
+    if shared.is_empty() {
+        return Seki::default();
+    }
+
+    let touching = geometry.adjacent(shared);
+    let chains: Vec<Group> = find_groups(board)
+        .into_iter()
+        .filter(|group| group.stones.iter().any(|p| touching.contains(p.index(size))))
+        .collect();
+
+    let stones = black | white;
+    let eyes = find_empty_regions(board)
+        .into_iter()
+        .filter(|region| region.border.len() == 1 && region.spots.len() <= MAX_EYE_SIZE)
+        .filter(|region| {
+            let spots: Bitboard = region.spots.iter().map(|p| p.index(size)).collect();
+            let enclosing = geometry.adjacent(spots) & stones;
+            chains.iter().any(|chain| {
+                enclosing.points(size).all(|stone| chain.stones.contains(&stone))
+            })
+        })
+        .collect();
+
+    Seki {
+        chains,
+        shared_liberties: shared.points(size).collect(),
+        eyes,
+    }
+}
+
+/// Returns whether a stone of `color` at `idx` would be left with at most one liberty
+/// without capturing any opposing stones.
+fn is_self_atari(position: &Position, idx: usize, color: &Occupant) -> bool {
+    let geometry = position.geometry();
+    let stone = Bitboard::single(idx);
+    let empty_after = position.empty() & !stone;
+
+    let opponent = if *color == Occupant::Black {
+        Occupant::White
+    } else {
+        Occupant::Black
+    };
+    let opponent_stones = position.stones(&opponent);
+    let captures = geometry
+        .adjacent(stone)
+        .iter()
+        .filter(|&adj| opponent_stones.contains(adj))
+        .any(|adj| (position.liberties(position.chain_at(adj)) & empty_after).is_empty());
+    if captures {
+        return false;
+    }
+
+    let chain = geometry.flood_fill(stone, position.stones(color) | stone);
+    (geometry.adjacent(chain) & empty_after).count() <= 1
+}
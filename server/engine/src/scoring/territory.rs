@@ -0,0 +1,90 @@
+use crate::models::{Board, EmptyRegion, Occupant, Position, ScoringMethod};
+use super::seki::find_seki;
+use std::collections::HashSet;
+
+/// Finds contiguous regions of empty spaces on the board using a flood-fill algorithm.
+///
+/// This is a key function for scoring in Go, as it identifies potential territory.
+/// Each empty region is analyzed to determine:
+/// - All empty points in the region
+/// - Which player colors border the region
+/// - Whether the region touches the edge of the board
+///
+/// The flood fill runs on the board's bitboard [`Position`], so each region is found
+/// with a handful of shift operations rather than a point-by-point search.
+///
+/// # Arguments
+/// * `board` - The game board to analyze
+///
+/// # Returns
+/// A vector of EmptyRegion objects describing each connected empty area
+pub fn find_empty_regions(board: &Board) -> Vec<EmptyRegion> {
+    let position = Position::from_board(board);
+    let geometry = position.geometry();
+
+    position
+        .empty_regions()
+        .into_iter()
+        .map(|region| {
+            let adjacent = geometry.adjacent(region);
+            let mut border = HashSet::new();
+            for color in [Occupant::Black, Occupant::White] {
+                if !(adjacent & position.stones(&color)).is_empty() {
+                    border.insert(color);
+                }
+            }
+
+            EmptyRegion {
+                spots: region.points(board.board_size).collect(),
+                border,
+                touches_edge: !(region & geometry.edge()).is_empty(),
+            }
+        })
+        .collect()
+}
+
+/// Calculates the territory (empty intersections) for each player.
+///
+/// In Go scoring, an empty region is territory for a player if it is bordered by stones
+/// of only that player's color. Whether the region touches the edge of the board makes
+/// no difference: the edge encloses territory just like stones do. Dead stones must be
+/// taken off the board first, so that the region they sat in counts for the opponent.
+///
+/// Under territory scoring, the eyes of groups in seki are not territory (see
+/// [`find_seki`]); under area scoring they count for the player who owns them.
+///
+/// # Arguments
+/// * `board` - The game board to analyze
+/// * `method` - The scoring method, which decides whether eyes in seki count
+///
+/// # Returns
+/// A tuple (black_territory, white_territory) with the count of territory points for each player
+pub fn determine_territory(board: &Board, method: ScoringMethod) -> (u64, u64) {
+    let regions = find_empty_regions(board);
+    let seki = match method {
+        ScoringMethod::Territory => find_seki(board),
+        ScoringMethod::Area | ScoringMethod::TrompTaylor => Default::default(),
+    };
+    let mut black_territory = 0;
+    let mut white_territory = 0;
+    
+    for region in regions {
+        // Eyes of groups in seki are not territory under territory scoring
+        if region.spots.iter().any(|&point| seki.contains(point)) {
+            continue;
+        }
+
+        // If the region is bordered by stones of only one color, it's that player's territory
+        if region.border.len() == 1 {
+            let color = region.border.iter().next().unwrap();
+            match color {
+                Occupant::Black => black_territory += region.spots.len() as u64,
+                Occupant::White => white_territory += region.spots.len() as u64,
+                Occupant::Empty => {} // This shouldn't happen logically
+            }
+        }
+        // Regions with mixed borders (dame) don't count as territory for either player
+    }
+    
+    (black_territory, white_territory)
+}
\ No newline at end of file
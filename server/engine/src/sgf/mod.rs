@@ -0,0 +1,15 @@
+/*!
+ * Smart Game Format (SGF) support.
+ *
+ * SGF is the standard file format for recording Go games. This module
+ * converts games to SGF (FF[4]) so they can be opened in other Go programs,
+ * and reads the main line of SGF files, including positions set up along the
+ * way, so that games played elsewhere can be brought in for review. Comments,
+ * markup and variations are read along with the main line and written back out.
+ */
+
+pub mod parser;
+pub mod writer;
+
+pub use parser::*;
+pub use writer::*;
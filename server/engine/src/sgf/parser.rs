@@ -0,0 +1,542 @@
+use super::writer::{
+    escape_text, SgfComment, SgfGame, SgfMarkup, SgfMove, SgfSetup, SgfVariation,
+};
+use crate::models::{Occupant, Point, Ruleset, MAX_BOARD_SIZE};
+
+/// Properties whose values are lists of points, which may be compressed into
+/// rectangles such as `aa:cc`.
+const POINT_LIST_PROPERTIES: [&str; 11] = [
+    "AB", "AW", "AE", "CR", "SQ", "TR", "MA", "SL", "DD", "TB", "TW",
+];
+
+/// Markup properties that mark points with a shape, kept in game records along
+/// with labels (`LB`).
+const SHAPE_PROPERTIES: [&str; 4] = ["CR", "SQ", "TR", "MA"];
+
+/// Deepest nesting of variations a file may have, once each game tree followed by a
+/// single variation has been joined with it. Real game records stay far below this;
+/// the limit keeps hostile files from nesting the tree deep enough to overflow the
+/// stack when it is dropped or compared.
+pub const MAX_VARIATION_DEPTH: usize = 500;
+
+/// A node of an SGF game tree.
+///
+/// Property values are stored unescaped, in the order they appear in the file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SgfNode {
+    /// The node's properties, as identifiers with their values
+    pub properties: Vec<(String, Vec<String>)>,
+}
+
+/// A game tree as SGF writes it: a sequence of nodes, followed by the variations
+/// that branch off after the last node.
+///
+/// The first variation continues the main line, so a sequence followed by a single
+/// variation is stored as one longer sequence.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SgfBranch {
+    /// The nodes of the sequence, in order; never empty
+    pub nodes: Vec<SgfNode>,
+
+    /// The game trees that follow the last node, the main line first
+    pub variations: Vec<SgfBranch>,
+}
+
+/// A parsed and validated SGF game tree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SgfTree {
+    /// Size of the board, from the root's `SZ` property (19 if absent)
+    pub board_size: u8,
+
+    /// The whole tree, starting with the root node
+    pub tree: SgfBranch,
+}
+
+impl SgfNode {
+    /// Returns all values of a property, or an empty slice if the node doesn't have it.
+    pub fn values(&self, ident: &str) -> &[String] {
+        self.properties
+            .iter()
+            .find(|(name, _)| name == ident)
+            .map(|(_, values)| values.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Returns the first value of a property, if the node has it.
+    pub fn get(&self, ident: &str) -> Option<&str> {
+        self.values(ident).first().map(String::as_str)
+    }
+
+    /// Returns the move made in this node, if any.
+    ///
+    /// # Returns
+    /// * `Ok(Some((color, point)))` - The move, with None as the point for a pass
+    /// * `Ok(None)` - If the node has no move
+    /// * `Err(message)` - If the node has moves for both players or an invalid point
+    pub fn sgf_move(&self, board_size: u8) -> Result<Option<(Occupant, Option<Point>)>, String> {
+        let (color, value) = match (self.get("B"), self.get("W")) {
+            (Some(_), Some(_)) => {
+                return Err("A node cannot contain moves for both players".to_string())
+            }
+            (Some(value), None) => (Occupant::Black, value),
+            (None, Some(value)) => (Occupant::White, value),
+            (None, None) => return Ok(None),
+        };
+        // Older files write a pass as "tt" on boards up to 19x19
+        if value.is_empty() || (value == "tt" && board_size <= 19) {
+            return Ok(Some((color, None)));
+        }
+        let point = Point::from_sgf(value, board_size)?;
+        Ok(Some((color, Some(point))))
+    }
+}
+
+impl SgfBranch {
+    /// Writes the game tree back out as SGF text, e.g. `(;W[dd];B[cc])`.
+    pub fn to_sgf(&self) -> String {
+        let mut sgf = String::new();
+        self.write(&mut sgf);
+        sgf
+    }
+
+    /// Appends the game tree to `sgf`. Trees are nested at most
+    /// [`MAX_VARIATION_DEPTH`] deep, which bounds the recursion.
+    fn write(&self, sgf: &mut String) {
+        sgf.push('(');
+        for node in &self.nodes {
+            sgf.push(';');
+            for (ident, values) in &node.properties {
+                sgf.push_str(ident);
+                for value in values {
+                    sgf.push_str(&format!("[{}]", escape_text(value)));
+                }
+            }
+        }
+        for variation in &self.variations {
+            variation.write(sgf);
+        }
+        sgf.push(')');
+    }
+}
+
+impl SgfTree {
+    /// Returns the root node, holding the game information.
+    pub fn root(&self) -> &SgfNode {
+        &self.tree.nodes[0]
+    }
+
+    /// Returns the nodes of the main line: the root and each first variation after it.
+    pub fn main_line(&self) -> Vec<&SgfNode> {
+        let mut nodes = Vec::new();
+        let mut branch = &self.tree;
+        loop {
+            nodes.extend(&branch.nodes);
+            match branch.variations.first() {
+                Some(next) => branch = next,
+                None => return nodes,
+            }
+        }
+    }
+
+    /// Reads the main line of the tree as a game record.
+    ///
+    /// Setup stones in the root node become the game's starting position: Black's
+    /// as its handicap stones and White's as setup stones. Stones added or removed
+    /// anywhere else, and stones removed in the root, are kept as setup steps to
+    /// apply before the next move. Comments and markup (`CR`, `SQ`, `TR`, `MA` and
+    /// `LB`) are kept with the move they follow, and variations are kept as SGF text
+    /// along with the move they branch off after. Time settings and any other
+    /// properties are not part of the record.
+    ///
+    /// # Returns
+    /// * `Ok(game)` - The game record
+    /// * `Err(message)` - If the komi or handicap is malformed
+    pub fn to_game(&self) -> Result<SgfGame, String> {
+        let root = self.root();
+        let komi = match root.get("KM") {
+            Some(komi) => komi
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid komi: {}", komi))?,
+            None => 0.0,
+        };
+        let handicap = match root.get("HA") {
+            Some(handicap) => handicap
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid handicap: {}", handicap))?,
+            None => 0,
+        };
+        let first_player = match root.get("PL") {
+            Some("B") => Some(Occupant::Black),
+            Some("W") => Some(Occupant::White),
+            Some(player) => return Err(format!("Invalid player to move: {}", player)),
+            None => None,
+        };
+
+        let mut game = SgfGame {
+            board_size: self.board_size,
+            komi,
+            ruleset: root
+                .get("RU")
+                .and_then(Ruleset::from_sgf_name)
+                .unwrap_or_default(),
+            handicap,
+            handicap_stones: parse_point_list(root.values("AB"), self.board_size)?,
+            setup_white: parse_point_list(root.values("AW"), self.board_size)?,
+            first_player,
+            player_black: root.get("PB").map(str::to_string),
+            player_white: root.get("PW").map(str::to_string),
+            date: root.get("DT").map(str::to_string),
+            time_control: None,
+            result: root.get("RE").and_then(|result| result.parse().ok()),
+            moves: Vec::new(),
+            setup: Vec::new(),
+            comments: Vec::new(),
+            markup: Vec::new(),
+            variations: Vec::new(),
+        };
+
+        // Follow the main line through each branch point, keeping the other
+        // variations with the number of moves played before them
+        let mut branch = &self.tree;
+        let mut is_root = true;
+        loop {
+            for node in &branch.nodes {
+                self.read_node(&mut game, node, is_root)?;
+                is_root = false;
+            }
+            for variation in branch.variations.iter().skip(1) {
+                game.variations.push(SgfVariation {
+                    move_number: game.moves.len(),
+                    sgf: variation.to_sgf(),
+                });
+            }
+            match branch.variations.first() {
+                Some(next) => branch = next,
+                None => return Ok(game),
+            }
+        }
+    }
+
+    /// Adds a node of the main line to a game record: its setup stones and move, then
+    /// its comment and markup.
+    fn read_node(&self, game: &mut SgfGame, node: &SgfNode, is_root: bool) -> Result<(), String> {
+        // The root's added stones are the starting position
+        let (black, white) = if is_root {
+            (Vec::new(), Vec::new())
+        } else {
+            (
+                parse_point_list(node.values("AB"), self.board_size)?,
+                parse_point_list(node.values("AW"), self.board_size)?,
+            )
+        };
+        let setup = SgfSetup {
+            before_move: game.moves.len(),
+            black,
+            white,
+            empty: parse_point_list(node.values("AE"), self.board_size)?,
+        };
+        if !(setup.black.is_empty() && setup.white.is_empty() && setup.empty.is_empty()) {
+            game.setup.push(setup);
+        }
+        if let Some((color, point)) = node.sgf_move(self.board_size)? {
+            game.moves.push(SgfMove {
+                color,
+                point,
+                played_at: node.get("TS").map(str::to_string),
+            });
+        }
+
+        let move_number = game.moves.len();
+        if let Some(text) = node.get("C") {
+            game.comments.push(SgfComment {
+                move_number,
+                text: text.to_string(),
+            });
+        }
+        for shape in SHAPE_PROPERTIES {
+            for point in parse_point_list(node.values(shape), self.board_size)? {
+                game.markup.push(SgfMarkup {
+                    move_number,
+                    shape: shape.to_string(),
+                    point,
+                    label: None,
+                });
+            }
+        }
+        for value in node.values("LB") {
+            let (point, label) = value.split_once(':').unwrap_or((value, ""));
+            game.markup.push(SgfMarkup {
+                move_number,
+                shape: "LB".to_string(),
+                point: Point::from_sgf(point, self.board_size)?,
+                label: Some(label.to_string()),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Parses a list of points, expanding compressed rectangles such as `aa:cc`.
+///
+/// # Arguments
+/// * `values` - The property values
+/// * `board_size` - Size of the board, for bounds checking
+///
+/// # Returns
+/// * `Ok(points)` - Every point in the list
+/// * `Err(message)` - If a value is not a point or rectangle on the board
+pub fn parse_point_list(values: &[String], board_size: u8) -> Result<Vec<Point>, String> {
+    let mut points = Vec::new();
+    for value in values {
+        match value.split_once(':') {
+            Some((from, to)) => {
+                let from = Point::from_sgf(from, board_size)?;
+                let to = Point::from_sgf(to, board_size)?;
+                for row in from.row.min(to.row)..=from.row.max(to.row) {
+                    for col in from.col.min(to.col)..=from.col.max(to.col) {
+                        points.push(Point::new(row, col));
+                    }
+                }
+            }
+            None => points.push(Point::from_sgf(value, board_size)?),
+        }
+    }
+    Ok(points)
+}
+
+/// Reads the board size from the root's `SZ` property.
+fn board_size(root: &SgfNode) -> Result<u8, String> {
+    let Some(value) = root.get("SZ") else {
+        return Ok(19);
+    };
+    let invalid = || format!("Invalid board size: {}", value);
+    let (columns, rows) = value.split_once(':').unwrap_or((value, value));
+    let columns: u8 = columns.trim().parse().map_err(|_| invalid())?;
+    let rows: u8 = rows.trim().parse().map_err(|_| invalid())?;
+    if columns != rows {
+        return Err(format!("Only square boards are supported, not {}", value));
+    }
+    if !(2..=MAX_BOARD_SIZE).contains(&columns) {
+        return Err(format!(
+            "Board size must be between 2 and {}, not {}",
+            MAX_BOARD_SIZE, columns
+        ));
+    }
+    Ok(columns)
+}
+
+/// Checks that every move, setup stone and markup point in the tree is on the board.
+fn validate_points(tree: &SgfBranch, board_size: u8) -> Result<(), String> {
+    let mut stack = vec![tree];
+    while let Some(branch) = stack.pop() {
+        for node in &branch.nodes {
+            node.sgf_move(board_size)?;
+            for (ident, values) in &node.properties {
+                let invalid = |e: String| format!("{} in {}", e, ident);
+                match ident.as_str() {
+                    ident if POINT_LIST_PROPERTIES.contains(&ident) => {
+                        parse_point_list(values, board_size).map_err(invalid)?;
+                    }
+                    // Arrows and lines join two points; labels put text on a point
+                    "AR" | "LN" | "LB" => {
+                        for value in values {
+                            let (from, to) = value
+                                .split_once(':')
+                                .ok_or_else(|| format!("Invalid value {} in {}", value, ident))?;
+                            Point::from_sgf(from, board_size).map_err(invalid)?;
+                            if ident != "LB" {
+                                Point::from_sgf(to, board_size).map_err(invalid)?;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        stack.extend(&branch.variations);
+    }
+    Ok(())
+}
+
+/// Reads SGF text one character at a time.
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!("Expected '{}' but found '{}'", expected, c)),
+            None => Err(format!("Expected '{}' but the file ended", expected)),
+        }
+    }
+
+    /// Parses a game tree: a sequence of nodes followed by its variations.
+    ///
+    /// Nested game trees are kept on a stack of their own rather than parsed
+    /// recursively, and may nest at most [`MAX_VARIATION_DEPTH`] deep.
+    fn game_tree(&mut self) -> Result<SgfBranch, String> {
+        self.expect('(')?;
+        // The game trees opened so far, innermost last, each with the depth of its
+        // deepest variation read so far
+        let mut open = vec![(SgfBranch::default(), 0)];
+        loop {
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some(';') => {
+                    let (branch, _) = open.last_mut().unwrap();
+                    if !branch.variations.is_empty() {
+                        return Err("A node cannot follow the variations of a game tree".to_string());
+                    }
+                    branch.nodes.push(self.node()?);
+                }
+                Some('(') => {
+                    if open.last().unwrap().0.nodes.is_empty() {
+                        return Err("A game tree must contain at least one node".to_string());
+                    }
+                    open.push((SgfBranch::default(), 0));
+                }
+                Some(')') => {
+                    let (mut branch, variation_depth) = open.pop().unwrap();
+                    if branch.nodes.is_empty() {
+                        return Err("A game tree must contain at least one node".to_string());
+                    }
+
+                    // A single variation just continues the sequence
+                    let depth = if branch.variations.len() == 1 {
+                        let next = branch.variations.pop().unwrap();
+                        branch.nodes.extend(next.nodes);
+                        branch.variations = next.variations;
+                        variation_depth
+                    } else {
+                        variation_depth + 1
+                    };
+                    if depth > MAX_VARIATION_DEPTH {
+                        return Err(format!(
+                            "Variations are nested more than {} deep",
+                            MAX_VARIATION_DEPTH
+                        ));
+                    }
+
+                    match open.last_mut() {
+                        Some((parent, deepest)) => {
+                            parent.variations.push(branch);
+                            *deepest = (*deepest).max(depth);
+                        }
+                        None => return Ok(branch),
+                    }
+                }
+                Some(c) => return Err(format!("Expected ';', '(' or ')' but found '{}'", c)),
+                None => return Err("Expected ')' but the file ended".to_string()),
+            }
+        }
+    }
+
+    /// Parses the properties of a node, after its `;`.
+    fn node(&mut self) -> Result<SgfNode, String> {
+        let mut node = SgfNode::default();
+        loop {
+            self.skip_whitespace();
+            let mut ident = String::new();
+            while let Some(c) = self.chars.next_if(|c| c.is_ascii_alphabetic()) {
+                // Older files may mix in lowercase letters, which are not part of the name
+                if c.is_ascii_uppercase() {
+                    ident.push(c);
+                }
+            }
+            if ident.is_empty() {
+                return Ok(node);
+            }
+
+            let mut values = Vec::new();
+            loop {
+                self.skip_whitespace();
+                if self.chars.peek() != Some(&'[') {
+                    break;
+                }
+                values.push(self.value()?);
+            }
+            if values.is_empty() {
+                return Err(format!("Property {} has no value", ident));
+            }
+            match node.properties.iter_mut().find(|(name, _)| *name == ident) {
+                Some((_, existing)) => existing.extend(values),
+                None => node.properties.push((ident, values)),
+            }
+        }
+    }
+
+    /// Parses a bracketed property value, removing escapes and soft line breaks.
+    fn value(&mut self) -> Result<String, String> {
+        self.expect('[')?;
+        let mut value = String::new();
+        loop {
+            match self.chars.next() {
+                Some(']') => return Ok(value),
+                Some('\\') => match self.chars.next() {
+                    // A backslash before a line break joins the lines
+                    Some('\n') => {
+                        self.chars.next_if_eq(&'\r');
+                    }
+                    Some('\r') => {
+                        self.chars.next_if_eq(&'\n');
+                    }
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                Some(c) => value.push(c),
+                None => break,
+            }
+        }
+        Err("Unterminated property value".to_string())
+    }
+}
+
+/// Parses an SGF file into a validated game tree.
+///
+/// Only the first game of a collection is read. The tree must be for Go (`GM[1]`)
+/// on a square board of a supported size, and every move, setup stone and markup
+/// point must be on the board.
+///
+/// # Arguments
+/// * `text` - The contents of the SGF file
+///
+/// # Returns
+/// * `Ok(tree)` - The game tree, including variations, comments and markup
+/// * `Err(message)` - If the file is malformed or invalid
+pub fn parse_sgf(text: &str) -> Result<SgfTree, String> {
+    let mut parser = Parser {
+        chars: text.chars().peekable(),
+    };
+    let tree = parser.game_tree()?;
+
+    let root = &tree.nodes[0];
+    if let Some(game) = root.get("GM") {
+        if game.trim() != "1" {
+            return Err(format!("Not a game of Go (GM[{}])", game));
+        }
+    }
+    let board_size = board_size(root)?;
+    validate_points(&tree, board_size)?;
+    Ok(SgfTree { board_size, tree })
+}
+
+/// Reads the main line of an SGF file as a game record.
+///
+/// # Arguments
+/// * `text` - The contents of the SGF file
+///
+/// # Returns
+/// * `Ok(game)` - The game record (see [`SgfTree::to_game`])
+/// * `Err(message)` - If the file is malformed or the game cannot be read
+pub fn read_sgf(text: &str) -> Result<SgfGame, String> {
+    parse_sgf(text)?.to_game()
+}
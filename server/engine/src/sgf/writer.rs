@@ -0,0 +1,328 @@
+use crate::models::{GameResult, Occupant, Point, Ruleset, TimeControl};
+
+/// Name of this application, written to the `AP` property of exported games.
+const APPLICATION: &str = "GoTime";
+
+/// A move in a game record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SgfMove {
+    /// The player who moved
+    pub color: Occupant,
+
+    /// Where the stone was played, or None for a pass
+    pub point: Option<Point>,
+
+    /// When the move was played, as an RFC 3339 timestamp
+    pub played_at: Option<String>,
+}
+
+/// Stones added or removed between moves, for records that set up a position
+/// part way through the game.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SgfSetup {
+    /// Number of moves played before the setup
+    pub before_move: usize,
+
+    /// Points where Black stones are added
+    pub black: Vec<Point>,
+
+    /// Points where White stones are added
+    pub white: Vec<Point>,
+
+    /// Points cleared of stones
+    pub empty: Vec<Point>,
+}
+
+/// A comment on a position in a game record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SgfComment {
+    /// Number of moves played when the comment was made; 0 for the starting position
+    pub move_number: usize,
+
+    /// The comment, from the `C` property
+    pub text: String,
+}
+
+/// A mark drawn on a point of a position in a game record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SgfMarkup {
+    /// Number of moves played when the mark was drawn; 0 for the starting position
+    pub move_number: usize,
+
+    /// The markup property: "CR", "SQ", "TR" or "MA" for a circle, square, triangle
+    /// or cross, or "LB" for a label
+    pub shape: String,
+
+    /// The marked point
+    pub point: Point,
+
+    /// The text of a label
+    pub label: Option<String>,
+}
+
+/// A line of play branching off the main line of a game record.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SgfVariation {
+    /// Number of main-line moves played before the variation branches off
+    pub move_number: usize,
+
+    /// The variation as an SGF game tree, e.g. `(;W[dd];B[cc])`
+    pub sgf: String,
+}
+
+/// A complete game record, with everything that is written to an SGF file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SgfGame {
+    /// Size of the board
+    pub board_size: u8,
+
+    /// Points added to White's score
+    pub komi: f32,
+
+    /// The ruleset the game is played under
+    pub ruleset: Ruleset,
+
+    /// Number of handicap stones Black receives
+    pub handicap: u8,
+
+    /// Black's handicap stones, placed before the first move
+    pub handicap_stones: Vec<Point>,
+
+    /// White stones placed before the first move, for games set up from a position
+    pub setup_white: Vec<Point>,
+
+    /// The player to make the first move, if it is not clear from the moves
+    pub first_player: Option<Occupant>,
+
+    /// Name of the Black player
+    pub player_black: Option<String>,
+
+    /// Name of the White player
+    pub player_white: Option<String>,
+
+    /// The date the game was played, as `YYYY-MM-DD`
+    pub date: Option<String>,
+
+    /// The time control, for timed games
+    pub time_control: Option<TimeControl>,
+
+    /// The result, for finished games
+    pub result: Option<GameResult>,
+
+    /// The moves played after the handicap stones, in order
+    pub moves: Vec<SgfMove>,
+
+    /// Stones added or removed after the starting position, in order
+    pub setup: Vec<SgfSetup>,
+
+    /// Comments on the main line, in order
+    pub comments: Vec<SgfComment>,
+
+    /// Markup on the main line, in order
+    pub markup: Vec<SgfMarkup>,
+
+    /// Variations branching off the main line, in order
+    pub variations: Vec<SgfVariation>,
+}
+
+/// Escapes a value for an SGF text property, where `]` and `\` must be escaped.
+pub(crate) fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == ']' || c == '\\' {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Formats a duration in milliseconds as seconds, the unit SGF uses for time.
+fn seconds(ms: u64) -> String {
+    (ms as f64 / 1000.0).to_string()
+}
+
+/// Describes the overtime of a time control in the usual `OT` notation, e.g.
+/// `"5x30 byo-yomi"`, or None if there is no overtime.
+fn overtime(control: &TimeControl) -> Option<String> {
+    match *control {
+        TimeControl::Absolute { .. } => None,
+        TimeControl::ByoYomi {
+            period_ms, periods, ..
+        } => Some(format!("{}x{} byo-yomi", periods, seconds(period_ms))),
+        TimeControl::Canadian {
+            period_ms, stones, ..
+        } => Some(format!("{}/{} Canadian", stones, seconds(period_ms))),
+        TimeControl::Fischer { increment_ms, .. } => {
+            Some(format!("{} fischer", seconds(increment_ms)))
+        }
+    }
+}
+
+/// Returns the main time of a time control.
+fn main_time_ms(control: &TimeControl) -> u64 {
+    match *control {
+        TimeControl::Absolute { main_time_ms }
+        | TimeControl::ByoYomi { main_time_ms, .. }
+        | TimeControl::Canadian { main_time_ms, .. }
+        | TimeControl::Fischer { main_time_ms, .. } => main_time_ms,
+    }
+}
+
+/// Writes a game record as an SGF (FF[4]) file.
+///
+/// The root node holds the game information and the stones placed before the first
+/// move (`AB` and `AW`), followed by one node per move. Passes are written as empty moves (`B[]`), and
+/// each move records when it was played in the private `TS` property, which other
+/// programs ignore. Setup between moves gets a node of its own (`AB`, `AW` and `AE`).
+/// Comments and markup go on the root or the move they belong to, and variations
+/// branch off just before the next move, after any setup.
+///
+/// # Arguments
+/// * `game` - The game record to write
+///
+/// # Returns
+/// The SGF text, ending with a newline
+pub fn write_sgf(game: &SgfGame) -> String {
+    let mut sgf = format!(
+        "(;FF[4]GM[1]CA[UTF-8]AP[{}]SZ[{}]KM[{}]RU[{}]",
+        APPLICATION,
+        game.board_size,
+        game.komi,
+        game.ruleset.sgf_name()
+    );
+    if game.handicap > 0 {
+        sgf.push_str(&format!("HA[{}]", game.handicap));
+    }
+    if let Some(name) = &game.player_black {
+        sgf.push_str(&format!("PB[{}]", escape_text(name)));
+    }
+    if let Some(name) = &game.player_white {
+        sgf.push_str(&format!("PW[{}]", escape_text(name)));
+    }
+    if let Some(date) = &game.date {
+        sgf.push_str(&format!("DT[{}]", date));
+    }
+    if let Some(control) = &game.time_control {
+        sgf.push_str(&format!("TM[{}]", seconds(main_time_ms(control))));
+        if let Some(overtime) = overtime(control) {
+            sgf.push_str(&format!("OT[{}]", overtime));
+        }
+    }
+    if let Some(result) = &game.result {
+        sgf.push_str(&format!("RE[{}]", result));
+    }
+    write_points(&mut sgf, "AB", &game.handicap_stones);
+    write_points(&mut sgf, "AW", &game.setup_white);
+    if let Some(player) = &game.first_player {
+        sgf.push_str(if *player == Occupant::White { "PL[W]" } else { "PL[B]" });
+    }
+    write_annotations(&mut sgf, game, 0);
+    sgf.push('\n');
+
+    // Each branch point nests the rest of the main line in a game tree of its own,
+    // followed by the variations once the main line is closed
+    let mut setup = game.setup.iter().peekable();
+    let mut branches = Vec::new();
+    for (i, mv) in game.moves.iter().enumerate() {
+        while let Some(step) = setup.next_if(|step| step.before_move <= i) {
+            write_setup(&mut sgf, step);
+        }
+        if let Some(variations) = variations_at(game, i) {
+            sgf.push('(');
+            branches.push(variations);
+        }
+        let color = if mv.color == Occupant::White { "W" } else { "B" };
+        let point = mv.point.map(|p| p.to_sgf()).unwrap_or_default();
+        sgf.push_str(&format!(";{}[{}]", color, point));
+        if let Some(played_at) = &mv.played_at {
+            sgf.push_str(&format!("TS[{}]", played_at));
+        }
+        write_annotations(&mut sgf, game, i + 1);
+        sgf.push('\n');
+    }
+    for step in setup {
+        write_setup(&mut sgf, step);
+    }
+    // Variations after the last move need an empty node to stand for the main line
+    if let Some(variations) = variations_at(game, game.moves.len()) {
+        sgf.push_str("(;");
+        branches.push(variations);
+    }
+    while let Some(variations) = branches.pop() {
+        sgf.push_str(")\n");
+        for variation in variations {
+            sgf.push_str(&variation.sgf);
+            sgf.push('\n');
+        }
+    }
+    sgf.push_str(")\n");
+    sgf
+}
+
+/// Returns the variations branching off after `move_number` moves, if there are any.
+fn variations_at(game: &SgfGame, move_number: usize) -> Option<Vec<&SgfVariation>> {
+    let variations: Vec<_> = game
+        .variations
+        .iter()
+        .filter(|variation| variation.move_number == move_number)
+        .collect();
+    (!variations.is_empty()).then_some(variations)
+}
+
+/// Writes the comments and markup made after `move_number` moves into the current
+/// node. Several comments are joined into one, and a point keeps only its first mark,
+/// as SGF allows one per node.
+fn write_annotations(sgf: &mut String, game: &SgfGame, move_number: usize) {
+    let comments: Vec<_> = game
+        .comments
+        .iter()
+        .filter(|comment| comment.move_number == move_number)
+        .map(|comment| escape_text(&comment.text))
+        .collect();
+    if !comments.is_empty() {
+        sgf.push_str(&format!("C[{}]", comments.join("\n\n")));
+    }
+
+    let mut marked = Vec::new();
+    let mut marks: Vec<(&str, Vec<String>)> = Vec::new();
+    for mark in game.markup.iter().filter(|mark| mark.move_number == move_number) {
+        if marked.contains(&mark.point) {
+            continue;
+        }
+        marked.push(mark.point);
+        let value = match &mark.label {
+            Some(label) => format!("{}:{}", mark.point.to_sgf(), escape_text(label)),
+            None => mark.point.to_sgf(),
+        };
+        match marks.iter_mut().find(|(shape, _)| *shape == mark.shape) {
+            Some((_, values)) => values.push(value),
+            None => marks.push((&mark.shape, vec![value])),
+        }
+    }
+    for (shape, values) in marks {
+        sgf.push_str(shape);
+        for value in values {
+            sgf.push_str(&format!("[{}]", value));
+        }
+    }
+}
+
+/// Writes a point list property, unless there are no points.
+fn write_points(sgf: &mut String, ident: &str, points: &[Point]) {
+    if !points.is_empty() {
+        sgf.push_str(ident);
+        for point in points {
+            sgf.push_str(&format!("[{}]", point.to_sgf()));
+        }
+    }
+}
+
+/// Writes a node with the stones added and removed by a setup step.
+fn write_setup(sgf: &mut String, setup: &SgfSetup) {
+    sgf.push(';');
+    write_points(sgf, "AB", &setup.black);
+    write_points(sgf, "AW", &setup.white);
+    write_points(sgf, "AE", &setup.empty);
+    sgf.push('\n');
+}
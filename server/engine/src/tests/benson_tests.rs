@@ -0,0 +1,162 @@
+use crate::models::{GroupStatus, Occupant, Point, ScoringMethod};
+use crate::scoring::{
+    calculate_score, count_prisoners, find_dead_stones, find_pass_alive, remove_dead_stones,
+};
+use crate::tests::test_utils::create_board_from_string;
+
+/// A Black wall with two eyes, each holding a White stone that can never live,
+/// above a White wall with a single open region below it.
+const TWO_EYES: &str = "
+    W.B.W
+    ..B..
+    BBBBB
+    WWWWW
+    .....
+";
+
+/// Tests that a chain with two vital regions is pass-alive and makes them vital.
+#[test]
+fn test_two_eyes_are_pass_alive() {
+    let board = create_board_from_string(TWO_EYES, 5);
+
+    let black = find_pass_alive(&board, &Occupant::Black);
+    assert_eq!(black.chains.len(), 1);
+    assert_eq!(black.chains[0].stones.len(), 7);
+    assert_eq!(black.vital_regions.len(), 2);
+    assert!(black.vital_regions[0].contains(&Point::new(0, 0)));
+    assert!(black.vital_regions[1].contains(&Point::new(0, 4)));
+
+    // White's only region is vital to its wall, but one region is not enough
+    let white = find_pass_alive(&board, &Occupant::White);
+    assert!(white.chains.is_empty());
+    assert!(white.vital_regions.is_empty());
+
+    assert_eq!(find_dead_stones(&board), vec![Point::new(0, 0), Point::new(0, 4)]);
+}
+
+/// Tests that a chain with a single eye, or an eye too big to be vital, is not pass-alive.
+#[test]
+fn test_single_eye_is_not_pass_alive() {
+    let one_eye = create_board_from_string(
+        "
+        W...W
+        .....
+        BBBBB
+        WWWWW
+        .....
+        ",
+        5,
+    );
+    assert!(find_pass_alive(&one_eye, &Occupant::Black).chains.is_empty());
+    assert!(find_dead_stones(&one_eye).is_empty());
+
+    // The second eye has an empty point the chain doesn't touch
+    let big_eye = create_board_from_string(
+        "
+        ..B..
+        ..B..
+        BBBBB
+        WWWWW
+        .....
+        ",
+        5,
+    );
+    let black = find_pass_alive(&big_eye, &Occupant::Black);
+    assert!(black.chains.is_empty());
+}
+
+/// Tests that chains lose their status when a region they rely on also borders a
+/// chain that is not alive.
+#[test]
+fn test_regions_bordering_unsettled_chains_are_discarded() {
+    // The region at (0, 2)-(1, 2) is vital to both upper chains, which each have a
+    // second eye in the corner. But it also borders the lone stone at (2, 2), which
+    // has no vital region, so it is discarded, and with it both upper chains.
+    let board = create_board_from_string(
+        "
+        .B.B.
+        BB.BB
+        ..B..
+        .....
+        .....
+        ",
+        5,
+    );
+    let black = find_pass_alive(&board, &Occupant::Black);
+    assert!(black.chains.is_empty());
+    assert!(black.vital_regions.is_empty());
+
+    // Joining the lone stone to a chain that cannot be captured settles everything
+    let settled = create_board_from_string(
+        "
+        .B.B.
+        BB.BB
+        .BBB.
+        BB.BB
+        .B.B.
+        ",
+        5,
+    );
+    let black = find_pass_alive(&settled, &Occupant::Black);
+    assert_eq!(black.chains.len(), 1);
+    assert_eq!(black.vital_regions.len(), 8);
+}
+
+/// Tests annotating stones with their group status.
+#[test]
+fn test_annotate_group_status() {
+    let mut board = create_board_from_string(TWO_EYES, 5);
+    board.annotate_group_status();
+
+    let status = |row, col| board.get(Point::new(row, col)).unwrap().group_status;
+    assert_eq!(status(2, 0), Some(GroupStatus::Alive));
+    assert_eq!(status(0, 2), Some(GroupStatus::Alive));
+    assert_eq!(status(0, 0), Some(GroupStatus::Dead));
+    assert_eq!(status(0, 4), Some(GroupStatus::Dead));
+    assert_eq!(status(3, 0), None);
+    assert_eq!(status(1, 0), None);
+}
+
+/// Tests that removing dead stones takes only the unconditionally dead groups off
+/// the board, keeping the position hash up to date.
+#[test]
+fn test_remove_dead_stones() {
+    let mut board = create_board_from_string(TWO_EYES, 5);
+    let removed = remove_dead_stones(&mut board);
+    assert_eq!(removed.len(), 2);
+    assert!(removed.iter().all(|group| group.occupant == Occupant::White));
+    assert_eq!(count_prisoners(&removed), (2, 0));
+
+    for point in [Point::new(0, 0), Point::new(0, 4)] {
+        let spot = board.get(point).unwrap();
+        assert_eq!(spot.occupant, Occupant::Empty);
+        assert_eq!(spot.marker.as_deref(), Some("removed"));
+    }
+    // The White wall is not alive either, but it is not dead
+    assert_eq!(board.get(Point::new(3, 0)).unwrap().occupant, Occupant::White);
+
+    let mut rehashed = board.clone();
+    rehashed.refresh_hash();
+    assert_eq!(board.position_hash(), rehashed.position_hash());
+
+    // Nothing else is dead afterwards
+    assert!(remove_dead_stones(&mut board).is_empty());
+}
+
+/// Tests that the points of removed stones count as territory for the player
+/// surrounding them.
+#[test]
+fn test_dead_stone_removal_scoring() {
+    let mut board = create_board_from_string(TWO_EYES, 5);
+    let prisoners = count_prisoners(&remove_dead_stones(&mut board));
+
+    // Black has 7 stones and 8 points in its eyes; White has 5 stones and 5 points
+    let (black_area, white_area) = calculate_score(&board, ScoringMethod::Area, 6.5, prisoners);
+    assert_eq!(black_area, 7.0 + 8.0);
+    assert_eq!(white_area, 5.0 + 5.0 + 6.5);
+
+    let (black_territory, white_territory) =
+        calculate_score(&board, ScoringMethod::Territory, 6.5, prisoners);
+    assert_eq!(black_territory, 8.0 + 2.0);
+    assert_eq!(white_territory, 5.0 + 6.5);
+}
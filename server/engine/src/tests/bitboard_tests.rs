@@ -0,0 +1,116 @@
+use crate::models::{Bitboard, Chains, Geometry, Occupant, Position};
+use crate::tests::test_utils::create_board_from_string;
+
+/// Tests that adjacency never wraps from one row's last column into the next row.
+#[test]
+fn test_adjacent_does_not_wrap_columns() {
+    let geometry = Geometry::new(9);
+
+    // Last column of row 2 and first column of row 3 are consecutive indices
+    let right_edge = Bitboard::single(2 * 9 + 8);
+    let adjacent: Vec<usize> = geometry.adjacent(right_edge).iter().collect();
+    assert_eq!(adjacent, vec![9 + 8, 2 * 9 + 7, 3 * 9 + 8]);
+
+    let left_edge = Bitboard::single(3 * 9);
+    let adjacent: Vec<usize> = geometry.adjacent(left_edge).iter().collect();
+    assert_eq!(adjacent, vec![2 * 9, 3 * 9 + 1, 4 * 9]);
+}
+
+/// Tests adjacency in the corners of the largest board, whose points span every word.
+#[test]
+fn test_adjacent_on_largest_board() {
+    let geometry = Geometry::new(25);
+    let last = 25 * 25 - 1;
+
+    let corner: Vec<usize> = geometry.adjacent(Bitboard::single(last)).iter().collect();
+    assert_eq!(corner, vec![last - 25, last - 1]);
+
+    let origin: Vec<usize> = geometry.adjacent(Bitboard::single(0)).iter().collect();
+    assert_eq!(origin, vec![1, 25]);
+
+    // A point straddling a 64-bit word boundary
+    let straddling: Vec<usize> = geometry.adjacent(Bitboard::single(64)).iter().collect();
+    assert_eq!(straddling, vec![39, 63, 65, 89]);
+}
+
+/// Tests that flood fill stays within the given points and follows only orthogonal links.
+#[test]
+fn test_flood_fill() {
+    let board = create_board_from_string(
+        "
+        BB...
+        .B...
+        ..B..
+        ...BB
+        .....
+        ",
+        5,
+    );
+    let position = Position::from_board(&board);
+    let black = position.stones(&Occupant::Black);
+    let geometry = position.geometry();
+
+    let chain = geometry.flood_fill(Bitboard::single(0), black);
+    assert_eq!(chain.iter().collect::<Vec<_>>(), vec![0, 1, 6]);
+
+    // Diagonal stones are separate chains
+    let chain = geometry.flood_fill(Bitboard::single(12), black);
+    assert_eq!(chain.iter().collect::<Vec<_>>(), vec![12]);
+    assert_eq!(position.chains().len(), 3);
+}
+
+/// Tests that a position hashes identically to the board it was built from.
+#[test]
+fn test_position_hash_matches_board() {
+    let board = create_board_from_string(
+        "
+        .B.W.
+        BW...
+        ..B..
+        W...B
+        .....
+        ",
+        5,
+    );
+    let mut position = Position::from_board(&board);
+    assert_eq!(position.position_hash(), board.position_hash());
+    assert_eq!(Position::from_occupants(&position.occupants(), 5), position);
+
+    // Removing and restoring stones returns to the same hash
+    let stones = position.stones(&Occupant::White);
+    position.remove(stones);
+    assert_ne!(position.position_hash(), board.position_hash());
+    for idx in stones.iter() {
+        position.set(idx, &Occupant::White);
+    }
+    assert_eq!(position.position_hash(), board.position_hash());
+}
+
+/// Tests liberties and empty regions of a small position.
+#[test]
+fn test_liberties_and_empty_regions() {
+    let board = create_board_from_string(
+        "
+        .B...
+        B....
+        .....
+        .....
+        .....
+        ",
+        5,
+    );
+    let position = Position::from_board(&board);
+
+    // The corner point is cut off from the rest of the empty board
+    let regions = position.empty_regions();
+    assert_eq!(regions.len(), 2);
+    assert_eq!(regions[0], Bitboard::single(0));
+    assert_eq!(regions[1].count(), 22);
+
+    let liberties = position.liberties(position.chain_at(1));
+    assert_eq!(liberties.iter().collect::<Vec<_>>(), vec![0, 2, 6]);
+
+    let chains = Chains::from_position(&position);
+    assert_eq!(chains.liberty_count(5), Some(3));
+    assert_eq!(chains.liberty_count(0), None);
+}
@@ -0,0 +1,128 @@
+use crate::bot::{accepts_dead_stones, choose_bot_move, BotInput, BotMove};
+use crate::models::{Board, Occupant, Point, Ruleset};
+use crate::tests::test_utils::{create_board_from_string, create_empty_board};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Builds the bot's view of a board with no history, komi or prisoners.
+fn input<'a>(board: &'a Board, to_move: &'a Occupant) -> BotInput<'a> {
+    BotInput {
+        board,
+        to_move,
+        ruleset: Ruleset::Chinese,
+        position_history: &[],
+        komi: 0.0,
+        captures: (0, 0),
+        move_count: 0,
+        opponent_passed: false,
+    }
+}
+
+/// Tests that the bot captures stones in atari.
+#[test]
+fn test_bot_captures() {
+    let board = create_board_from_string(
+        "
+        WB...
+        .....
+        .....
+        .....
+        .....
+        ",
+        5,
+    );
+    let mut rng = StdRng::seed_from_u64(1);
+    let bot_move = choose_bot_move(&input(&board, &Occupant::Black), &mut rng);
+    assert_eq!(bot_move, BotMove::Play(Point::new(1, 0)));
+}
+
+/// Tests that the bot never fills its own eyes, and passes when nothing else is left.
+#[test]
+fn test_bot_keeps_own_eyes() {
+    let board = create_board_from_string(
+        "
+        BBB
+        B.B
+        BBB
+        ",
+        3,
+    );
+    let mut rng = StdRng::seed_from_u64(1);
+    let bot_move = choose_bot_move(&input(&board, &Occupant::Black), &mut rng);
+    assert_eq!(bot_move, BotMove::Pass);
+}
+
+/// Tests that the bot answers a pass with a pass unless it is behind.
+#[test]
+fn test_bot_passes_back() {
+    let board = create_empty_board(5);
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut view = input(&board, &Occupant::Black);
+    view.opponent_passed = true;
+    assert_eq!(choose_bot_move(&view, &mut rng), BotMove::Pass);
+
+    // Behind on komi, Black plays on
+    view.komi = 6.5;
+    assert!(matches!(choose_bot_move(&view, &mut rng), BotMove::Play(_)));
+}
+
+/// Tests that the bot resigns a hopeless game, but not too early.
+#[test]
+fn test_bot_resigns_when_hopeless() {
+    let board = create_board_from_string(
+        "
+        .W.W.
+        WWWWW
+        WWWWW
+        WWWWW
+        WWWWW
+        ",
+        5,
+    );
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut view = input(&board, &Occupant::Black);
+    view.move_count = 20;
+    assert_eq!(choose_bot_move(&view, &mut rng), BotMove::Resign);
+
+    view.move_count = 10;
+    assert_ne!(choose_bot_move(&view, &mut rng), BotMove::Resign);
+}
+
+/// Tests that the same seed always gives the same move, played on the third or
+/// fourth line of an empty board.
+#[test]
+fn test_bot_is_deterministic() {
+    let board = create_empty_board(9);
+    let view = input(&board, &Occupant::Black);
+    let first = choose_bot_move(&view, &mut StdRng::seed_from_u64(42));
+    let second = choose_bot_move(&view, &mut StdRng::seed_from_u64(42));
+    assert_eq!(first, second);
+
+    let BotMove::Play(point) = first else {
+        panic!("Expected a move on an empty board, got {:?}", first);
+    };
+    let line = point.row.min(point.col).min(8 - point.row).min(8 - point.col);
+    assert!(line == 2 || line == 3, "{:?} is not on the third or fourth line", point);
+}
+
+/// Tests that the bot accepts only the dead stones it can prove dead itself.
+#[test]
+fn test_bot_accepts_proven_dead_stones() {
+    let board = create_board_from_string(
+        "
+        W.B.W
+        ..B..
+        BBBBB
+        .....
+        .....
+        ",
+        5,
+    );
+    let dead = [Point::new(0, 4), Point::new(0, 0)];
+    assert!(accepts_dead_stones(&board, &dead));
+
+    // Leaving the dead stones on the board, or taking off the living wall, is refused
+    assert!(!accepts_dead_stones(&board, &[]));
+    let wall: Vec<Point> = (0..5).map(|col| Point::new(2, col)).collect();
+    assert!(!accepts_dead_stones(&board, &[&dead[..], &wall].concat()));
+}
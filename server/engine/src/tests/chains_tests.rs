@@ -0,0 +1,149 @@
+use crate::models::{Board, Chains, Occupant, Point, Ruleset};
+use crate::tests::test_utils::{create_board_from_string, create_board_from_vec, create_empty_board};
+use crate::utils::{apply_move_to_board, get_group_indices};
+
+/// Tests that building chains from a board finds each chain and its liberties.
+#[test]
+fn test_chains_from_board() {
+    let board = create_board_from_string(
+        "
+        BB...
+        B.W..
+        ..W..
+        .....
+        ....B
+        ",
+        5,
+    );
+    let chains = Chains::from_board(&board);
+
+    assert_eq!(chains.chain_stones(0).map(|s| s.count()), Some(3));
+    assert_eq!(chains.liberty_count(1), Some(3));
+    assert_eq!(chains.liberty_count(Point::new(1, 2).index(5)), Some(6));
+    assert_eq!(chains.liberty_count(24), Some(2));
+    assert_eq!(chains.liberty_count(6), None, "Empty points have no chain");
+    assert_eq!(chains.position_hash(), board.position_hash());
+}
+
+/// Tests that playing a stone merges chains and captures opponent chains without liberties.
+#[test]
+fn test_chains_play_merges_and_captures() {
+    let board = create_board_from_string(
+        "
+        .BW..
+        B.BW.
+        .BW..
+        .....
+        .....
+        ",
+        5,
+    );
+    let mut chains = Chains::from_board(&board);
+
+    // White captures the Black stone at (2, 1) by playing at (1, 1)
+    let effect = chains.play(Point::new(1, 1).index(5), Occupant::White);
+    assert_eq!(effect.captured, vec![Point::new(1, 2).index(5)]);
+    assert!(effect.suicided.is_empty());
+    assert_eq!(chains.occupants()[Point::new(1, 2).index(5)], Occupant::Empty);
+    assert_eq!(chains.liberty_count(Point::new(1, 1).index(5)), Some(1));
+
+    // Black connects its two stones through (0, 0)
+    chains.play(0, Occupant::Black);
+    assert_eq!(chains.chain_stones(0).map(|s| s.count()), Some(3));
+}
+
+/// Generates a deterministic pseudo-random sequence of point indices.
+fn pseudo_random_points(count: usize, points: usize, seed: u64) -> Vec<usize> {
+    let mut state = seed;
+    (0..count)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((state >> 33) as usize) % points
+        })
+        .collect()
+}
+
+/// Tests that chains maintained incrementally over a long game agree with chains
+/// rebuilt from scratch, and with the full-board group search.
+#[test]
+fn test_incremental_chains_match_rebuilt_chains() {
+    let size: u8 = 9;
+    let points = (size as usize).pow(2);
+    let mut chains = Chains::new(size);
+    let mut history = vec![chains.position_hash()];
+    let mut color = Occupant::Black;
+
+    for idx in pseudo_random_points(400, points, 7) {
+        if chains.check_move(idx, &color, Ruleset::Chinese, &history).is_err() {
+            continue;
+        }
+        chains.play(idx, color.clone());
+        history.push(chains.position_hash());
+        color = if color == Occupant::Black { Occupant::White } else { Occupant::Black };
+
+        let board = create_board_from_vec(chains.occupants().to_vec(), size);
+        let rebuilt = Chains::from_board(&board);
+        assert_eq!(chains.position_hash(), board.position_hash());
+        for point in 0..points {
+            assert_eq!(chains.liberty_count(point), rebuilt.liberty_count(point));
+            if board.spots[point].occupant != Occupant::Empty {
+                let start = Point::from_index(point, size).unwrap();
+                let group = get_group_indices(&board.spots, size, start);
+                assert_eq!(chains.chain_stones(point).map(|s| s.count()), Some(group.len() as u32));
+            }
+        }
+    }
+}
+
+/// Tests that the constant-time legality check agrees with actually applying each move.
+#[test]
+fn test_legal_moves_match_move_application() {
+    let board = create_board_from_string(
+        "
+        .BW.W
+        B.BWB
+        .BW.W
+        WW.BB
+        .B.W.
+        ",
+        5,
+    );
+    for ruleset in [Ruleset::Chinese, Ruleset::NewZealand] {
+        for color in [Occupant::Black, Occupant::White] {
+            let history = vec![board.position_hash()];
+            let legal = Chains::from_board(&board).legal_moves(&color, ruleset, &history);
+
+            for (idx, &is_legal) in legal.iter().enumerate() {
+                let applied = apply_move_to_board(
+                    board.spots.clone(),
+                    5,
+                    color.clone(),
+                    Point::from_index(idx, 5).unwrap(),
+                    &history,
+                    ruleset,
+                    0,
+                );
+                assert_eq!(is_legal, applied.is_ok(), "Mismatch at {} for {:?}", idx, color);
+            }
+        }
+    }
+}
+
+/// Tests that legality for a full 19×19 board can be computed for every point.
+#[test]
+fn test_legal_moves_on_full_size_board() {
+    let mut board: Board = create_empty_board(19);
+    for idx in pseudo_random_points(150, 361, 11) {
+        let occupant = if idx % 2 == 0 { Occupant::Black } else { Occupant::White };
+        board.spots[idx].occupant = occupant;
+    }
+    board.refresh_hash();
+
+    let legal = Chains::from_board(&board).legal_moves(&Occupant::Black, Ruleset::Chinese, &[]);
+    assert_eq!(legal.len(), 361);
+    for (idx, spot) in board.spots.iter().enumerate() {
+        if spot.occupant != Occupant::Empty {
+            assert!(!legal[idx], "Occupied points are never legal");
+        }
+    }
+}
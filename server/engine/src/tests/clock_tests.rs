@@ -0,0 +1,130 @@
+use crate::models::{Clock, TimeControl};
+
+const SECOND: u64 = 1000;
+
+/// Tests that an absolute clock only counts down and flags when it reaches zero.
+#[test]
+fn test_absolute_clock() {
+    let control = TimeControl::Absolute {
+        main_time_ms: 60 * SECOND,
+    };
+    let mut clock = control.initial_clock();
+
+    clock.charge_move(&control, 45 * SECOND).unwrap();
+    assert_eq!(clock.time_remaining(&control), 15 * SECOND);
+    assert!(!clock.is_flagged(&control, 14 * SECOND));
+    assert!(clock.is_flagged(&control, 15 * SECOND));
+
+    assert!(clock.charge_move(&control, 20 * SECOND).is_err());
+    assert_eq!(clock.main_time_ms, 15 * SECOND, "A flagged move should not change the clock");
+}
+
+/// Tests that byo-yomi periods are only lost when used up completely.
+#[test]
+fn test_byo_yomi_clock() {
+    let control = TimeControl::ByoYomi {
+        main_time_ms: 10 * SECOND,
+        period_ms: 30 * SECOND,
+        periods: 3,
+    };
+    let mut clock = control.initial_clock();
+    assert_eq!(clock.time_remaining(&control), 100 * SECOND);
+
+    // Running 20s into byo-yomi uses no period
+    clock.charge_move(&control, 30 * SECOND).unwrap();
+    assert_eq!(clock.main_time_ms, 0);
+    assert_eq!(clock.periods, 3);
+
+    // Taking 65s uses up two periods and moves in the third
+    clock.charge_move(&control, 65 * SECOND).unwrap();
+    assert_eq!(clock.periods, 1);
+    assert_eq!(clock.time_remaining(&control), 30 * SECOND);
+
+    // Using up the last period loses on time
+    assert!(clock.is_flagged(&control, 30 * SECOND));
+    assert!(clock.charge_move(&control, 30 * SECOND).is_err());
+}
+
+/// Tests that Canadian overtime requires a number of stones per period and then
+/// starts the period over.
+#[test]
+fn test_canadian_clock() {
+    let control = TimeControl::Canadian {
+        main_time_ms: 5 * SECOND,
+        period_ms: 60 * SECOND,
+        stones: 3,
+    };
+    let mut clock = control.initial_clock();
+
+    // Moves in main time don't count towards the overtime stones
+    clock.charge_move(&control, 2 * SECOND).unwrap();
+    assert_eq!(clock.stones, 3);
+
+    // The first overtime move spends 10s of the period
+    clock.charge_move(&control, 13 * SECOND).unwrap();
+    assert_eq!(clock.main_time_ms, 0);
+    assert_eq!((clock.period_ms, clock.stones), (50 * SECOND, 2));
+
+    clock.charge_move(&control, 20 * SECOND).unwrap();
+    assert_eq!((clock.period_ms, clock.stones), (30 * SECOND, 1));
+
+    // Playing the last stone of the period starts a fresh one
+    clock.charge_move(&control, 29 * SECOND).unwrap();
+    assert_eq!((clock.period_ms, clock.stones), (60 * SECOND, 3));
+
+    clock.charge_move(&control, 40 * SECOND).unwrap();
+    assert!(clock.charge_move(&control, 20 * SECOND).is_err());
+}
+
+/// Tests that a Fischer clock gains its increment after every move made in time.
+#[test]
+fn test_fischer_clock() {
+    let control = TimeControl::Fischer {
+        main_time_ms: 60 * SECOND,
+        increment_ms: 10 * SECOND,
+    };
+    let mut clock = control.initial_clock();
+
+    clock.charge_move(&control, 5 * SECOND).unwrap();
+    assert_eq!(clock.main_time_ms, 65 * SECOND);
+    clock.charge_move(&control, 60 * SECOND).unwrap();
+    assert_eq!(clock.main_time_ms, 15 * SECOND);
+    assert!(clock.charge_move(&control, 15 * SECOND).is_err());
+}
+
+/// Tests the JSON form time controls are stored in, and rejecting unusable ones.
+#[test]
+fn test_time_control_json_and_validation() {
+    let json = r#"{"type":"byo_yomi","main_time_ms":600000,"period_ms":30000,"periods":5}"#;
+    let control: TimeControl = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        control,
+        TimeControl::ByoYomi {
+            main_time_ms: 600_000,
+            period_ms: 30_000,
+            periods: 5,
+        }
+    );
+    assert_eq!(serde_json::to_string(&control).unwrap(), json);
+    assert!(control.validate().is_ok());
+
+    let clock_json = serde_json::to_string(&control.initial_clock()).unwrap();
+    let clock: Clock = serde_json::from_str(&clock_json).unwrap();
+    assert_eq!(clock.periods, 5);
+
+    assert!(TimeControl::Absolute { main_time_ms: 0 }.validate().is_err());
+    assert!(TimeControl::Canadian {
+        main_time_ms: 0,
+        period_ms: 300_000,
+        stones: 0,
+    }
+    .validate()
+    .is_err());
+    assert!(TimeControl::ByoYomi {
+        main_time_ms: 0,
+        period_ms: 10_000,
+        periods: 3,
+    }
+    .validate()
+    .is_ok());
+}
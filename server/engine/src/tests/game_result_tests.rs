@@ -0,0 +1,58 @@
+use crate::models::{GameResult, Occupant, ResultReason};
+
+/// Tests that counted games produce the winner and margin in standard notation.
+#[test]
+fn test_result_from_score() {
+    let result = GameResult::from_score(40.0, 43.5);
+    assert_eq!(result.winner, Some(Occupant::White));
+    assert_eq!(result.margin, Some(3.5));
+    assert_eq!(result.reason, ResultReason::Score);
+    assert_eq!(result.to_notation(), "W+3.5");
+
+    assert_eq!(GameResult::from_score(52.0, 45.0).to_notation(), "B+7");
+
+    let jigo = GameResult::from_score(45.0, 45.0);
+    assert_eq!(jigo.winner, None);
+    assert_eq!(jigo.reason, ResultReason::Jigo);
+    assert_eq!(jigo.to_notation(), "0");
+}
+
+/// Tests the notation of games decided without counting.
+#[test]
+fn test_result_without_counting() {
+    let resigned = GameResult::win_by(Occupant::Black, ResultReason::Resignation);
+    assert_eq!(resigned.to_notation(), "B+R");
+    assert_eq!(resigned.margin, None);
+    assert_eq!(
+        GameResult::win_by(Occupant::White, ResultReason::Timeout).to_notation(),
+        "W+T"
+    );
+    assert_eq!(
+        GameResult::win_by(Occupant::Black, ResultReason::Forfeit).to_string(),
+        "B+F"
+    );
+}
+
+/// Tests parsing results, including the long SGF forms.
+#[test]
+fn test_parse_result() {
+    for notation in ["B+R", "W+T", "B+F", "W+3.5", "B+12", "0"] {
+        let parsed: GameResult = notation.parse().unwrap();
+        assert_eq!(parsed.to_notation(), notation);
+    }
+
+    assert_eq!(
+        "W+Resign".parse::<GameResult>(),
+        Ok(GameResult::win_by(Occupant::White, ResultReason::Resignation))
+    );
+    assert_eq!("b+time".parse::<GameResult>().unwrap().to_notation(), "B+T");
+    assert_eq!("Draw".parse::<GameResult>(), Ok(GameResult::jigo()));
+
+    let unknown_margin: GameResult = "B+".parse().unwrap();
+    assert_eq!(unknown_margin.winner, Some(Occupant::Black));
+    assert_eq!(unknown_margin.margin, None);
+
+    assert!("Void".parse::<GameResult>().is_err());
+    assert!("X+R".parse::<GameResult>().is_err());
+    assert!("W+lots".parse::<GameResult>().is_err());
+}
@@ -0,0 +1,472 @@
+use crate::models::{position_key, Occupant, Point, Ruleset};
+use crate::tests::test_utils::{create_board_from_string, create_empty_board};
+use crate::utils::{apply_move_to_board, position_hash};
+
+/// Tests that a legal move is successfully applied to the board.
+#[test]
+fn test_legal_move() {
+    let size = 9;
+    let board = create_empty_board(size).spots;
+    let timestamp = 1000;
+    
+    // Place a black stone at the center of the board
+    let (new_board, new_board_str, _) = apply_move_to_board(
+        board, 
+        size, 
+        Occupant::Black, 
+        Point::new(4, 4), 
+        &[], 
+        Ruleset::Chinese,
+        timestamp
+    ).expect("Legal move should succeed");
+    
+    // Verify the stone was placed correctly
+    let idx = Point::new(4, 4).index(size);
+    assert_eq!(
+        new_board[idx].occupant,
+        Occupant::Black,
+        "Black stone should be at (4,4)"
+    );
+    assert!(new_board_str.is_some(), "Board string should be updated");
+}
+
+/// Tests that placing a stone on an already occupied position fails.
+#[test]
+fn test_move_on_occupied() {
+    let size = 9;
+    let board = create_empty_board(size).spots;
+    let timestamp = 1000;
+    
+    // First place a black stone
+    let (board, _, _) = apply_move_to_board(
+        board, 
+        size, 
+        Occupant::Black, 
+        Point::new(2, 2), 
+        &[], 
+        Ruleset::Chinese,
+        timestamp
+    ).unwrap();
+    
+    // Then try to place a white stone in the same position
+    let result = apply_move_to_board(
+        board, 
+        size, 
+        Occupant::White, 
+        Point::new(2, 2), 
+        &[], 
+        Ruleset::Chinese,
+        timestamp + 1
+    );
+    
+    assert!(result.is_err(), "Should not allow move on an occupied spot");
+    assert!(
+        result.unwrap_err().contains("occupied"),
+        "Error message should mention the spot is occupied"
+    );
+}
+
+/// Tests that stones with no liberties are captured.
+#[test]
+fn test_capture() {
+    let size = 5;
+    let board_str = "
+        .....
+        .....
+        ..W..
+        .....
+        .....
+    ";
+    let mut board = create_board_from_string(board_str, size).spots;
+    let ts = 1000;
+    
+    // Surround the white stone with black stones
+    board = apply_move_to_board(board, size, Occupant::Black, Point::new(1, 2), &[], Ruleset::Chinese, ts + 1).unwrap().0;
+    board = apply_move_to_board(board, size, Occupant::Black, Point::new(2, 1), &[], Ruleset::Chinese, ts + 2).unwrap().0;
+    board = apply_move_to_board(board, size, Occupant::Black, Point::new(2, 3), &[], Ruleset::Chinese, ts + 3).unwrap().0;
+    
+    // Complete the capture with the final black stone
+    let (new_board, _, effect) = apply_move_to_board(board, size, Occupant::Black, Point::new(3, 2), &[], Ruleset::Chinese, ts + 4).unwrap();
+    assert_eq!(effect.captured, vec![Point::new(2, 2).index(size)], "Black should take one prisoner");
+    
+    // Verify the white stone was captured (removed)
+    let idx_white = Point::new(2, 2).index(size);
+    assert_eq!(
+        new_board[idx_white].occupant,
+        Occupant::Empty,
+        "White stone should be captured"
+    );
+    assert!(
+        new_board[idx_white].marker.is_some(),
+        "Captured spot should be marked"
+    );
+}
+
+/// Tests that self_capture moves (placing a stone with no liberties) are rejected.
+#[test]
+fn test_self_capture() {
+    // Create a board with black stones surrounding an empty spot
+    let board_str = "
+        .....
+        ..B..
+        .B.B.
+        ..B..
+        .....
+    ";
+    let board = create_board_from_string(board_str, 5).spots;
+    let ts = 1000;
+    
+    // Try to place a white stone in the surrounded empty spot
+    let result = apply_move_to_board(board, 5, Occupant::White, Point::new(2, 2), &[], Ruleset::Chinese, ts);
+    
+    assert!(result.is_err(), "self_capture move should be rejected");
+    assert!(
+        result.unwrap_err().contains("self_capture"),
+        "Error message should mention self_capture"
+    );
+}
+
+/// Tests that the ko rule prevents immediate recapture.
+#[test]
+fn test_ko_rule() {
+    // Create an empty board
+    let mut board = create_empty_board(9).spots;
+    let ts = 1000;
+    
+    // Set up the Ko pattern with symmetrical stones
+    // This creates a situation where a capture will lead to a Ko
+    
+    // Place Black stones on three sides of center
+    board[Point::new(3, 2).index(9)].occupant = Occupant::Black; // C4
+    board[Point::new(2, 3).index(9)].occupant = Occupant::Black; // D3
+    board[Point::new(3, 4).index(9)].occupant = Occupant::Black; // E4
+    
+    // Place White stones symmetrically opposite
+    board[Point::new(4, 2).index(9)].occupant = Occupant::White; // C5
+    board[Point::new(4, 4).index(9)].occupant = Occupant::White; // E5
+    board[Point::new(5, 3).index(9)].occupant = Occupant::White; // D6
+    
+    // Place the White stone that will be captured
+    board[Point::new(3, 3).index(9)].occupant = Occupant::White; // D4
+    
+    // Print the initial board state
+    println!("Initial board state with Ko pattern:");
+    for y in 0..9 {
+        let mut row_str = String::new();
+        for x in 0..9 {
+            let idx = Point::new(y, x).index(9);
+            let stone = match board[idx].occupant {
+                Occupant::Black => "B",
+                Occupant::White => "W",
+                Occupant::Empty => "."
+            };
+            row_str.push_str(stone);
+        }
+        println!("{}", row_str);
+    }
+    
+    // Track every position reached so far, starting with the initial one
+    let mut history = vec![position_hash(&board, 9)];
+    
+    // Black captures the White stone at D4 by playing at D5 (3,4)
+    let (board_after_capture, _, _) = apply_move_to_board(
+        board.clone(),
+        9,
+        Occupant::Black,
+        Point::new(4, 3), // D5
+        &history,
+        Ruleset::Chinese,
+        ts
+    ).unwrap_or_else(|e| panic!("Black's capture move failed: {}", e));
+    
+    // Print board after Black's capture
+    println!("\nAfter Black captures White by playing at D5:");
+    for y in 0..9 {
+        let mut row_str = String::new();
+        for x in 0..9 {
+            let idx = Point::new(y, x).index(9);
+            let stone = match board_after_capture[idx].occupant {
+                Occupant::Black => "B",
+                Occupant::White => "W",
+                Occupant::Empty => if board_after_capture[idx].marker.is_some() { "C" } else { "." }
+            };
+            row_str.push_str(stone);
+        }
+        println!("{}", row_str);
+    }
+    
+    // Verify the White stone at D4 was captured
+    let d4_idx = Point::new(3, 3).index(9);
+    assert_eq!(
+        board_after_capture[d4_idx].occupant,
+        Occupant::Empty,
+        "White stone at D4 should be captured"
+    );
+    
+    // Record this board state for ko detection
+    history.push(position_hash(&board_after_capture, 9));
+    
+    // White immediately retakes at D4, which would recreate the initial position
+    println!("\nWhite attempts to recapture at D4 (should violate Ko rule):");
+    let result = apply_move_to_board(
+        board_after_capture.clone(),
+        9,
+        Occupant::White,
+        Point::new(3, 3), // D4
+        &history,
+        Ruleset::Chinese,
+        ts + 1
+    );
+    
+    // This should fail due to Ko rule
+    assert!(result.is_err(), "Ko rule should prevent immediate recapture");
+    
+    let error_message = result.unwrap_err();
+    println!("Error message: '{}'", error_message);
+    
+    // Check that the error mentions the Ko rule
+    assert!(
+        error_message.contains("violates ko rule"),
+        "Error message should mention ko rule, got: '{}'", error_message
+    );
+    
+    // Without the earlier positions on record the same capture is an ordinary move
+    let (board_after_white, _, _) = apply_move_to_board(
+        board_after_capture,
+        9,
+        Occupant::White,
+        Point::new(3, 3), // D4
+        &[],
+        Ruleset::Chinese,
+        ts + 1
+    ).unwrap_or_else(|e| panic!("White's move at D4 failed: {}", e));
+    
+    let d5_idx = Point::new(4, 3).index(9);
+    assert_eq!(
+        board_after_white[d5_idx].occupant,
+        Occupant::Empty,
+        "Black stone at D5 should be captured"
+    );
+}
+
+/// Tests that superko rejects a move recreating a position from earlier than the
+/// immediately preceding one, as happens in longer cycles like triple ko.
+#[test]
+fn test_superko_rejects_older_position() {
+    let board_str = "
+        .....
+        .BW..
+        B.BW.
+        .BW..
+        .....
+    ";
+    let board = create_board_from_string(board_str, 5).spots;
+    let ts = 1000;
+    
+    // The position White would reach by capturing at (1, 2) occurred several moves ago
+    let after_capture_str = "
+        .....
+        .BW..
+        BW.W.
+        .BW..
+        .....
+    ";
+    let earlier_position = position_hash(&create_board_from_string(after_capture_str, 5).spots, 5);
+    let history = vec![
+        earlier_position,
+        position_hash(&create_empty_board(5).spots, 5),
+        position_hash(&board, 5),
+    ];
+    
+    let result = apply_move_to_board(board, 5, Occupant::White, Point::new(2, 1), &history, Ruleset::Chinese, ts);
+    
+    assert!(result.is_err(), "Recreating any earlier position should be rejected");
+    assert!(
+        result.unwrap_err().contains("superko"),
+        "Error message should name the superko rule"
+    );
+}
+
+/// Tests that a capture producing a position not seen before is allowed under superko.
+#[test]
+fn test_superko_allows_new_position() {
+    let board_str = "
+        .....
+        .BW..
+        B.BW.
+        .BW..
+        .....
+    ";
+    let board = create_board_from_string(board_str, 5).spots;
+    let history = vec![position_hash(&create_empty_board(5).spots, 5), position_hash(&board, 5)];
+    
+    let (new_board, _, _) = apply_move_to_board(board, 5, Occupant::White, Point::new(2, 1), &history, Ruleset::Chinese, 1000)
+        .expect("Capture leading to a new position should be legal");
+    
+    assert_eq!(
+        new_board[Point::new(2, 2).index(5)].occupant,
+        Occupant::Empty,
+        "Black stone in the ko should be captured"
+    );
+}
+
+/// Tests that the position hash depends only on stone placement, not on UI metadata.
+#[test]
+fn test_position_hash_ignores_annotations() {
+    let mut board = create_board_from_string("..B......", 3).spots;
+    let plain_hash = position_hash(&board, 3);
+    
+    board[0].marker = Some("captured".to_string());
+    board[1].playable = false;
+    board[2].move_number = Some(42);
+    assert_eq!(position_hash(&board, 3), plain_hash, "Annotations must not change the hash");
+    
+    board[3].occupant = Occupant::White;
+    assert_ne!(position_hash(&board, 3), plain_hash, "Different stones must change the hash");
+}
+
+/// A White group that would be left without liberties by playing at (3, 1).
+const SUICIDE_SHAPE: &str = "
+    BBBB.
+    BWW.B
+    BBBB.
+    .....
+    .....
+";
+
+/// Tests that multi-stone suicide is rejected under rulesets that forbid it.
+#[test]
+fn test_multi_stone_suicide_rejected() {
+    for ruleset in [Ruleset::Chinese, Ruleset::Japanese, Ruleset::Aga] {
+        let board = create_board_from_string(SUICIDE_SHAPE, 5).spots;
+        let result = apply_move_to_board(board, 5, Occupant::White, Point::new(1, 3), &[], ruleset, 1000);
+        assert!(
+            result.unwrap_err().contains("self_capture"),
+            "Suicide should be illegal under {} rules",
+            ruleset
+        );
+    }
+}
+
+/// Tests that multi-stone suicide removes the player's own group where the ruleset allows it.
+#[test]
+fn test_multi_stone_suicide_allowed() {
+    for ruleset in [Ruleset::NewZealand, Ruleset::TrompTaylor] {
+        let board = create_board_from_string(SUICIDE_SHAPE, 5).spots;
+        let (new_board, _, effect) =
+            apply_move_to_board(board, 5, Occupant::White, Point::new(1, 3), &[], ruleset, 1000)
+                .unwrap_or_else(|e| panic!("Suicide should be legal under {} rules: {}", ruleset, e));
+
+        for x in 1..=3 {
+            let idx = Point::new(1, x).index(5);
+            assert_eq!(new_board[idx].occupant, Occupant::Empty, "White group should be removed");
+            assert_eq!(new_board[idx].marker.as_deref(), Some("captured"));
+        }
+        assert_eq!(
+            new_board[Point::new(0, 3).index(5)].occupant,
+            Occupant::Black,
+            "Black stones should be untouched"
+        );
+
+        // The removed White stones are prisoners for Black, not captures by White
+        assert!(effect.captured.is_empty());
+        assert_eq!(effect.suicided.len(), 3);
+    }
+}
+
+/// Tests that single-stone suicide is rejected under positional superko because it
+/// recreates the current position, but is allowed under situational superko.
+#[test]
+fn test_single_stone_suicide_and_superko() {
+    let board_str = "
+        .....
+        ..B..
+        .B.B.
+        ..B..
+        .....
+    ";
+    let board = create_board_from_string(board_str, 5).spots;
+    let history = vec![position_key(position_hash(&board, 5), &Occupant::White)];
+
+    let result = apply_move_to_board(
+        board.clone(),
+        5,
+        Occupant::White,
+        Point::new(2, 2),
+        &history,
+        Ruleset::TrompTaylor,
+        1000,
+    );
+    assert!(result.unwrap_err().contains("superko"));
+
+    let result =
+        apply_move_to_board(board, 5, Occupant::White, Point::new(2, 2), &history, Ruleset::NewZealand, 1000);
+    assert!(result.is_ok(), "Single-stone suicide is a legal move under New Zealand rules");
+}
+
+/// Tests that the playability annotation agrees with the move path about suicide.
+#[test]
+fn test_playability_matches_suicide_rules() {
+    let multi_stone_idx = Point::new(1, 3).index(5);
+
+    let mut board = create_board_from_string(SUICIDE_SHAPE, 5);
+    board.annotate_playability(Occupant::White, Ruleset::Chinese, &[]);
+    assert!(!board.spots[multi_stone_idx].playable);
+
+    let mut board = create_board_from_string(SUICIDE_SHAPE, 5);
+    board.annotate_playability(Occupant::White, Ruleset::NewZealand, &[]);
+    assert!(board.spots[multi_stone_idx].playable);
+
+    let single_stone = "
+        .....
+        ..B..
+        .B.B.
+        ..B..
+        .....
+    ";
+    let single_stone_idx = Point::new(2, 2).index(5);
+
+    // Single-stone suicide recreates the current position, which positional superko forbids
+    let mut board = create_board_from_string(single_stone, 5);
+    let history = vec![position_key(board.position_hash(), &Occupant::White)];
+    board.annotate_playability(Occupant::White, Ruleset::TrompTaylor, &history);
+    assert!(!board.spots[single_stone_idx].playable);
+
+    let mut board = create_board_from_string(single_stone, 5);
+    board.annotate_playability(Occupant::White, Ruleset::NewZealand, &history);
+    assert!(board.spots[single_stone_idx].playable);
+}
+
+/// Tests that the playability annotation flags an immediate ko recapture as unplayable.
+#[test]
+fn test_playability_respects_ko() {
+    let before_capture = create_board_from_string(
+        "
+        .....
+        .BW..
+        B.BW.
+        .BW..
+        .....
+        ",
+        5,
+    );
+    let mut after_capture = create_board_from_string(
+        "
+        .....
+        .BW..
+        BW.W.
+        .BW..
+        .....
+        ",
+        5,
+    );
+    let history = vec![
+        position_key(before_capture.position_hash(), &Occupant::White),
+        position_key(after_capture.position_hash(), &Occupant::Black),
+    ];
+
+    after_capture.annotate_playability(Occupant::Black, Ruleset::Japanese, &history);
+
+    assert!(!after_capture.spots[Point::new(2, 2).index(5)].playable, "Ko recapture is illegal");
+    assert!(after_capture.spots[Point::new(4, 4).index(5)].playable, "Other moves remain legal");
+}
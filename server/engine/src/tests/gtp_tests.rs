@@ -0,0 +1,160 @@
+use crate::gtp::{run_gtp, GtpEngine};
+use crate::models::Ruleset;
+
+/// Runs a command on the engine, panicking if it fails.
+fn ok(engine: &mut GtpEngine, command: &str) -> String {
+    let mut words = command.split_whitespace();
+    let name = words.next().unwrap();
+    let args: Vec<&str> = words.collect();
+    engine.execute(name, &args).unwrap()
+}
+
+/// Tests the response format, including ids, comments and errors.
+#[test]
+fn test_gtp_responses() {
+    let mut engine = GtpEngine::default();
+    assert_eq!(engine.respond("protocol_version"), Some("= 2\n\n".to_string()));
+    assert_eq!(engine.respond("7 name # comment"), Some("=7 GoTime\n\n".to_string()));
+    assert_eq!(engine.respond("  # only a comment"), None);
+    assert_eq!(engine.respond(""), None);
+    assert_eq!(
+        engine.respond("3 frobnicate"),
+        Some("?3 unknown command\n\n".to_string())
+    );
+    assert_eq!(engine.respond("known_command\tgenmove"), Some("= true\n\n".to_string()));
+    assert_eq!(engine.respond("boardsize 30"), Some("? unacceptable size\n\n".to_string()));
+}
+
+/// Tests playing moves, captures and undo.
+#[test]
+fn test_gtp_play_and_undo() {
+    let mut engine = GtpEngine::default();
+    ok(&mut engine, "boardsize 5");
+    ok(&mut engine, "play b B1");
+    ok(&mut engine, "play w A1");
+    ok(&mut engine, "play b A2");
+    let board = ok(&mut engine, "showboard");
+    assert!(board.contains(" 2 X . . . . 2"));
+    assert!(board.contains(" 1 . X . . . 1"));
+    assert!(board.contains("Black (X) 1"));
+
+    // Occupied points and bad vertices are rejected
+    assert_eq!(engine.execute("play", &["w", "A2"]), Err("illegal move".to_string()));
+    assert_eq!(
+        engine.execute("play", &["w", "Z9"]),
+        Err("invalid coordinate".to_string())
+    );
+
+    ok(&mut engine, "undo");
+    let board = ok(&mut engine, "showboard");
+    assert!(board.contains(" 1 O X . . . 1"));
+    ok(&mut engine, "undo");
+    ok(&mut engine, "undo");
+    assert_eq!(engine.execute("undo", &[]), Err("cannot undo".to_string()));
+}
+
+/// Tests that the engine enforces ko through the ruleset.
+#[test]
+fn test_gtp_ko() {
+    let mut engine = GtpEngine::new(Ruleset::Japanese);
+    ok(&mut engine, "boardsize 5");
+    for command in [
+        "play b B1", "play w C1", "play b A2", "play w D2", "play b B3", "play w C3",
+        "play b C2", "play w B2",
+    ] {
+        ok(&mut engine, command);
+    }
+    // White just captured at B2; Black may not retake straight away
+    assert_eq!(engine.execute("play", &["b", "C2"]), Err("illegal move".to_string()));
+}
+
+/// Tests the handicap commands.
+#[test]
+fn test_gtp_handicap() {
+    let mut engine = GtpEngine::default();
+    assert_eq!(ok(&mut engine, "fixed_handicap 4"), "D4 Q16 Q4 D16");
+    assert_eq!(
+        engine.execute("fixed_handicap", &["2"]),
+        Err("board not empty".to_string())
+    );
+
+    ok(&mut engine, "clear_board");
+    ok(&mut engine, "boardsize 8");
+    assert_eq!(ok(&mut engine, "place_free_handicap 6"), "C3 F6 F3 C6");
+
+    ok(&mut engine, "clear_board");
+    ok(&mut engine, "set_free_handicap A1 B2 C3");
+    assert_eq!(
+        engine.execute("set_free_handicap", &["A1"]),
+        Err("board not empty".to_string())
+    );
+    ok(&mut engine, "clear_board");
+    assert_eq!(
+        engine.execute("set_free_handicap", &["A1", "A1"]),
+        Err("bad vertex list".to_string())
+    );
+}
+
+/// Tests counting and listing dead stones at the end of a game.
+#[test]
+fn test_gtp_final_score() {
+    let mut engine = GtpEngine::default();
+    ok(&mut engine, "boardsize 5");
+    ok(&mut engine, "komi 0.5");
+    // Black's wall on the C line has two eyes on its left; a White stone sits in one
+    for command in [
+        "play b C1", "play b C2", "play b C3", "play b C4", "play b C5", "play b A3",
+        "play b B3", "play b A4", "play w A1",
+    ] {
+        ok(&mut engine, command);
+    }
+    assert_eq!(ok(&mut engine, "final_status_list dead"), "A1");
+    assert_eq!(ok(&mut engine, "final_status_list seki"), "");
+    // The dead stone is removed, leaving the whole board to Black
+    assert_eq!(ok(&mut engine, "final_score"), "B+24.5");
+    assert!(engine.execute("final_status_list", &["maybe"]).is_err());
+}
+
+/// Tests that the score uses the komi the controller set, with no compensation added
+/// for handicap stones.
+#[test]
+fn test_gtp_final_score_with_handicap() {
+    let mut engine = GtpEngine::default();
+    ok(&mut engine, "boardsize 9");
+    ok(&mut engine, "komi 0.5");
+    ok(&mut engine, "fixed_handicap 2");
+    // Black's two stones and every empty point count for Black
+    assert_eq!(ok(&mut engine, "final_score"), "B+80.5");
+}
+
+/// Tests that generated moves are legal and never fill the player's own eye.
+#[test]
+fn test_gtp_genmove() {
+    let mut engine = GtpEngine::default();
+    ok(&mut engine, "boardsize 3");
+    ok(&mut engine, "play b B1");
+    ok(&mut engine, "play w A1");
+    // Black captures the White stone in atari
+    assert_eq!(ok(&mut engine, "genmove b"), "A2");
+
+    // Black surrounds every empty point, so there is nothing left to play
+    let mut engine = GtpEngine::default();
+    ok(&mut engine, "boardsize 3");
+    for command in ["play b A2", "play b B1", "play b B3", "play b C2", "play b B2"] {
+        ok(&mut engine, command);
+    }
+    assert_eq!(ok(&mut engine, "genmove b"), "pass");
+    // and every White move would be suicide
+    assert_eq!(ok(&mut engine, "genmove w"), "pass");
+}
+
+/// Tests a session read from input until `quit`.
+#[test]
+fn test_run_gtp() {
+    let mut engine = GtpEngine::default();
+    let input = "1 boardsize 9\n2 play black E5\n3 quit\n4 name\n";
+    let mut output = Vec::new();
+    run_gtp(&mut engine, input.as_bytes(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "=1 \n\n=2 \n\n=3 \n\n");
+    assert!(engine.has_quit());
+}
@@ -0,0 +1,97 @@
+use crate::models::{Occupant, Point};
+use crate::tests::test_utils::{create_board_from_string, create_empty_board};
+use crate::utils::{fixed_handicap_points, place_handicap_stone};
+
+/// Tests placing free handicap stones on distinct empty points.
+#[test]
+fn test_place_handicap_stone() {
+    let board = create_empty_board(9);
+    let board = place_handicap_stone(board.spots, 9, Point::new(2, 6)).unwrap();
+    assert_eq!(board[Point::new(2, 6).index(9)].occupant, Occupant::Black);
+    assert_eq!(board[Point::new(2, 6).index(9)].move_number, Some(0));
+
+    // Stones must be on distinct points on the board
+    let repeated = place_handicap_stone(board.clone(), 9, Point::new(2, 6));
+    assert!(repeated.unwrap_err().contains("already a stone"));
+    assert!(place_handicap_stone(board.clone(), 9, Point::new(9, 0)).is_err());
+
+    // Stones next to each other are fine
+    assert!(place_handicap_stone(board, 9, Point::new(2, 5)).is_ok());
+}
+
+/// Tests that a handicap stone may not fill its own group's last liberty.
+#[test]
+fn test_handicap_stone_needs_a_liberty() {
+    let board = create_board_from_string(
+        "
+        BBB
+        BBB
+        BB.
+        ",
+        3,
+    );
+    let result = place_handicap_stone(board.spots, 3, Point::new(2, 2));
+    assert!(result.unwrap_err().contains("liberty"));
+}
+
+/// Converts fixed handicap points to GTP vertices for easier comparison.
+fn fixed_handicap_vertices(board_size: u8, stones: u8) -> Vec<String> {
+    fixed_handicap_points(board_size, stones)
+        .unwrap()
+        .iter()
+        .map(|p| p.to_gtp(board_size))
+        .collect()
+}
+
+/// Tests the standard 19x19 handicap layouts for every stone count.
+#[test]
+fn test_fixed_handicap_19x19() {
+    assert_eq!(fixed_handicap_vertices(19, 2), ["D4", "Q16"]);
+    assert_eq!(fixed_handicap_vertices(19, 3), ["D4", "Q16", "Q4"]);
+    assert_eq!(fixed_handicap_vertices(19, 4), ["D4", "Q16", "Q4", "D16"]);
+    assert_eq!(fixed_handicap_vertices(19, 5), ["D4", "Q16", "Q4", "D16", "K10"]);
+    assert_eq!(
+        fixed_handicap_vertices(19, 6),
+        ["D4", "Q16", "Q4", "D16", "D10", "Q10"]
+    );
+    assert_eq!(
+        fixed_handicap_vertices(19, 7),
+        ["D4", "Q16", "Q4", "D16", "D10", "Q10", "K10"]
+    );
+    assert_eq!(
+        fixed_handicap_vertices(19, 8),
+        ["D4", "Q16", "Q4", "D16", "D10", "Q10", "K4", "K16"]
+    );
+    assert_eq!(
+        fixed_handicap_vertices(19, 9),
+        ["D4", "Q16", "Q4", "D16", "D10", "Q10", "K4", "K16", "K10"]
+    );
+}
+
+/// Tests that small boards use the 3-3 points and larger boards the 4-4 points.
+#[test]
+fn test_fixed_handicap_star_lines() {
+    assert_eq!(
+        fixed_handicap_vertices(9, 9),
+        ["C3", "G7", "G3", "C7", "C5", "G5", "E3", "E7", "E5"]
+    );
+    assert_eq!(fixed_handicap_vertices(12, 4), ["C3", "K10", "K3", "C10"]);
+    assert_eq!(fixed_handicap_vertices(13, 5), ["D4", "K10", "K4", "D10", "G7"]);
+    assert_eq!(fixed_handicap_vertices(7, 4), ["C3", "E5", "E3", "C5"]);
+    assert_eq!(fixed_handicap_vertices(25, 2), ["D4", "W22"]);
+}
+
+/// Tests the limits on board size and stone count.
+#[test]
+fn test_fixed_handicap_limits() {
+    assert!(fixed_handicap_points(6, 2).is_err());
+    assert!(fixed_handicap_points(26, 2).is_err());
+    assert!(fixed_handicap_points(19, 1).is_err());
+    assert!(fixed_handicap_points(19, 10).is_err());
+
+    // Even boards and 7x7 have no side or center points
+    assert!(fixed_handicap_points(7, 5).is_err());
+    assert!(fixed_handicap_points(18, 5).is_err());
+    assert!(fixed_handicap_points(18, 4).is_ok());
+    assert!(fixed_handicap_points(11, 9).is_ok());
+}
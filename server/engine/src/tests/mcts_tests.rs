@@ -0,0 +1,129 @@
+use crate::bot::{choose_mcts_move, BotInput, BotLevel, BotMove, MctsConfig};
+use crate::models::{Board, Occupant, Point, Ruleset};
+use crate::tests::test_utils::{create_board_from_string, create_empty_board};
+use std::time::{Duration, Instant};
+
+/// Builds the bot's view of a board with no history, komi or prisoners.
+fn input<'a>(board: &'a Board, to_move: &'a Occupant) -> BotInput<'a> {
+    BotInput {
+        board,
+        to_move,
+        ruleset: Ruleset::Chinese,
+        position_history: &[],
+        komi: 0.0,
+        captures: (0, 0),
+        move_count: 0,
+        opponent_passed: false,
+    }
+}
+
+/// A search that always plays its most visited move.
+fn config(playouts: u32, seed: u64) -> MctsConfig {
+    MctsConfig {
+        playouts,
+        temperature: 0.0,
+        max_moves: u64::MAX,
+        seed,
+    }
+}
+
+/// Tests that the same seed always gives the same move, and a different one may not.
+#[test]
+fn test_mcts_is_deterministic() {
+    let board = create_empty_board(9);
+    let view = input(&board, &Occupant::Black);
+    let level = BotLevel::Beginner.mcts_config(7);
+    let first = choose_mcts_move(&view, &level);
+    assert_eq!(first, choose_mcts_move(&view, &level));
+    assert!(matches!(first, BotMove::Play(_)));
+
+    let moves: Vec<BotMove> = (0..5)
+        .map(|seed| choose_mcts_move(&view, &BotLevel::Beginner.mcts_config(seed)))
+        .collect();
+    assert!(moves.iter().any(|&m| m != moves[0]), "Every seed played {:?}", moves[0]);
+}
+
+/// Tests that the search captures stones in atari before they can escape.
+#[test]
+fn test_mcts_captures() {
+    let board = create_board_from_string(
+        "
+        .....
+        .BBB.
+        BWWB.
+        .B...
+        .....
+        ",
+        5,
+    );
+    let bot_move = choose_mcts_move(&input(&board, &Occupant::Black), &config(1000, 1));
+    assert_eq!(bot_move, BotMove::Play(Point::new(3, 2)));
+}
+
+/// Tests that the search never fills its own eye, passing when nothing else is left.
+#[test]
+fn test_mcts_keeps_own_eyes() {
+    let board = create_board_from_string(
+        "
+        BBB
+        B.B
+        BBB
+        ",
+        3,
+    );
+    let bot_move = choose_mcts_move(&input(&board, &Occupant::Black), &config(50, 1));
+    assert_eq!(bot_move, BotMove::Pass);
+}
+
+/// Tests that the search resigns a lost game late enough, but not early on.
+#[test]
+fn test_mcts_resigns_when_hopeless() {
+    let board = create_board_from_string(
+        "
+        .W.W.
+        WWWWW
+        WWWWW
+        WWWWW
+        WWWWW
+        ",
+        5,
+    );
+    let mut view = input(&board, &Occupant::Black);
+    view.move_count = 20;
+    assert_eq!(choose_mcts_move(&view, &config(100, 1)), BotMove::Resign);
+
+    view.move_count = 2;
+    assert_ne!(choose_mcts_move(&view, &config(100, 1)), BotMove::Resign);
+}
+
+/// Tests parsing difficulty levels and that stronger levels search more.
+#[test]
+fn test_bot_levels() {
+    assert_eq!(" Medium ".parse::<BotLevel>(), Ok(BotLevel::Medium));
+    assert_eq!("hard".parse::<BotLevel>(), Ok(BotLevel::Hard));
+    assert_eq!(
+        "grandmaster".parse::<BotLevel>(),
+        Err("Unknown bot level: grandmaster".to_string())
+    );
+
+    for pair in BotLevel::ALL.windows(2) {
+        assert!(pair[0].playouts() < pair[1].playouts());
+        assert!(pair[0].max_moves() < pair[1].max_moves());
+        assert!(pair[0].temperature() >= pair[1].temperature());
+    }
+    assert_eq!(BotLevel::Hard.mcts_config(3).temperature, 0.0);
+}
+
+/// Tests that the strongest level stays quick on a 19×19 board, where its playouts
+/// are cut short by the move budget. A release build takes about half a second.
+#[test]
+fn test_mcts_hard_on_full_board() {
+    let board = create_empty_board(19);
+    let limit = if cfg!(debug_assertions) { 60 } else { 5 };
+    let start = Instant::now();
+    let config = BotLevel::Hard.mcts_config(1);
+    let bot_move = choose_mcts_move(&input(&board, &Occupant::Black), &config);
+    let elapsed = start.elapsed();
+    assert!(matches!(bot_move, BotMove::Play(_)), "Played {:?}", bot_move);
+    assert!(elapsed < Duration::from_secs(limit), "Took {:?}", elapsed);
+}
@@ -0,0 +1,27 @@
+/*!
+ * Unit and integration tests for the Go game.
+ * 
+ * This module contains tests for game logic, scoring mechanisms,
+ * and rule enforcement. It validates that the core gameplay functions
+ * work correctly under various scenarios.
+ */
+
+pub mod benson_tests;
+pub mod bot_tests;
+pub mod bitboard_tests;
+pub mod chains_tests;
+pub mod clock_tests;
+pub mod game_result_tests;
+pub mod game_tests;
+pub mod gtp_tests;
+pub mod handicap_tests;
+pub mod mcts_tests;
+pub mod point_tests;
+pub mod ruleset_tests;
+pub mod scoring_tests;
+pub mod seki_tests;
+pub mod sgf_tests;
+pub mod test_utils;
+pub mod zobrist_tests;
+
+pub use test_utils::*;
@@ -0,0 +1,74 @@
+use crate::models::{Bitboard, Point};
+
+/// Tests GTP vertex conversion, including the skipped "I" column and bottom-up rows.
+#[test]
+fn test_gtp_vertices() {
+    assert_eq!(Point::from_gtp("A1", 19), Ok(Point::new(18, 0)));
+    assert_eq!(Point::from_gtp("D4", 19), Ok(Point::new(15, 3)));
+    assert_eq!(Point::from_gtp("q16", 19), Ok(Point::new(3, 15)));
+    assert_eq!(Point::from_gtp("J9", 9), Ok(Point::new(0, 8)));
+    assert_eq!(Point::from_gtp("T19", 19), Ok(Point::new(0, 18)));
+
+    assert_eq!(Point::new(15, 3).to_gtp(19), "D4");
+    assert_eq!(Point::new(0, 8).to_gtp(9), "J9");
+    assert_eq!(Point::new(4, 4).to_gtp(9), "E5");
+
+    // "I" is never used, and vertices must lie on the board
+    assert!(Point::from_gtp("I5", 9).is_err());
+    assert!(Point::from_gtp("K1", 9).is_err());
+    assert!(Point::from_gtp("A10", 9).is_err());
+    assert!(Point::from_gtp("A0", 9).is_err());
+    assert!(Point::from_gtp("pass", 9).is_err());
+    assert!(Point::from_gtp("", 9).is_err());
+}
+
+/// Tests SGF coordinate conversion.
+#[test]
+fn test_sgf_coordinates() {
+    assert_eq!(Point::from_sgf("aa", 19), Ok(Point::new(0, 0)));
+    assert_eq!(Point::from_sgf("dp", 19), Ok(Point::new(15, 3)));
+    assert_eq!(Point::from_sgf("pd", 19), Ok(Point::new(3, 15)));
+    assert_eq!(Point::new(15, 3).to_sgf(), "dp");
+    assert_eq!(Point::new(18, 18).to_sgf(), "ss");
+
+    assert!(Point::from_sgf("", 19).is_err());
+    assert!(Point::from_sgf("tt", 19).is_err());
+    assert!(Point::from_sgf("jj", 9).is_err());
+    assert!(Point::from_sgf("DD", 19).is_err());
+    assert!(Point::from_sgf("abc", 19).is_err());
+}
+
+/// Tests that every notation round-trips through the same point on every board size.
+#[test]
+fn test_notations_round_trip() {
+    for board_size in 1..=25u8 {
+        for idx in 0..(board_size as usize).pow(2) {
+            let point = Point::from_index(idx, board_size).unwrap();
+            assert_eq!(point.index(board_size), idx);
+            assert_eq!(Point::from_gtp(&point.to_gtp(board_size), board_size), Ok(point));
+            assert_eq!(Point::from_sgf(&point.to_sgf(), board_size), Ok(point));
+        }
+    }
+}
+
+/// Tests bounds checking of point construction and neighbors at the edges.
+#[test]
+fn test_bounds_and_neighbors() {
+    assert_eq!(Point::on_board(2, 8, 9), Ok(Point::new(2, 8)));
+    assert!(Point::on_board(9, 0, 9).is_err());
+    assert!(Point::on_board(0, 9, 9).is_err());
+    assert!(Point::from_index(81, 9).is_err());
+    assert!(!Point::new(0, 9).is_on_board(9));
+
+    assert_eq!(
+        Point::new(0, 0).neighbors(9),
+        vec![Point::new(1, 0), Point::new(0, 1)]
+    );
+    assert_eq!(Point::new(4, 4).neighbors(9).len(), 4);
+    assert_eq!(Point::new(8, 3).neighbors(9).len(), 3);
+
+    // Bitboard points follow the same row-major layout
+    let bb: Bitboard = [0, 10, 80].into_iter().collect();
+    let points: Vec<Point> = bb.points(9).collect();
+    assert_eq!(points, vec![Point::new(0, 0), Point::new(1, 1), Point::new(8, 8)]);
+}
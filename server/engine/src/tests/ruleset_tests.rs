@@ -0,0 +1,117 @@
+use crate::models::{position_key, validate_komi, Occupant, Point, Ruleset, ScoringMethod};
+use crate::tests::test_utils::{create_board_from_string, create_empty_board};
+use crate::utils::{apply_move_to_board, position_hash};
+
+/// A ko shape where White can capture the Black stone at (2, 2) by playing at (1, 2).
+const KO_SHAPE: &str = "
+    .....
+    .BW..
+    B.BW.
+    .BW..
+    .....
+";
+
+/// The position after White captures in [`KO_SHAPE`].
+const KO_CAPTURED: &str = "
+    .....
+    .BW..
+    BW.W.
+    .BW..
+    .....
+";
+
+/// Plays White's capture in [`KO_SHAPE`] under the given ruleset and history.
+fn play_ko_capture(ruleset: Ruleset, history: &[u64]) -> Result<(), String> {
+    let board = create_board_from_string(KO_SHAPE, 5).spots;
+    apply_move_to_board(board, 5, Occupant::White, Point::new(2, 1), history, ruleset, 1000).map(|_| ())
+}
+
+/// Hash of the stone placement after White's capture.
+fn captured_hash() -> u64 {
+    position_hash(&create_board_from_string(KO_CAPTURED, 5).spots, 5)
+}
+
+/// Tests that ruleset identifiers round-trip and are parsed leniently.
+#[test]
+fn test_ruleset_parsing() {
+    for ruleset in Ruleset::ALL {
+        assert_eq!(ruleset.as_str().parse::<Ruleset>(), Ok(ruleset));
+    }
+    assert_eq!("New Zealand".parse::<Ruleset>(), Ok(Ruleset::NewZealand));
+    assert_eq!("Tromp-Taylor".parse::<Ruleset>(), Ok(Ruleset::TrompTaylor));
+    assert!("ancient".parse::<Ruleset>().unwrap_err().contains("Unknown ruleset"));
+}
+
+/// Tests that the simple ko rule only forbids recreating the position before the opponent's move.
+#[test]
+fn test_simple_ko_allows_older_repetition() {
+    let before_last_move = position_key(captured_hash(), &Occupant::Black);
+    let current = position_key(
+        position_hash(&create_board_from_string(KO_SHAPE, 5).spots, 5),
+        &Occupant::White,
+    );
+
+    // Immediate recapture is rejected
+    let result = play_ko_capture(Ruleset::Japanese, &[before_last_move, current]);
+    assert!(result.unwrap_err().contains("simple ko"));
+
+    // The same position from further back is allowed
+    let empty = position_key(position_hash(&create_empty_board(5).spots, 5), &Occupant::White);
+    let result = play_ko_capture(Ruleset::Japanese, &[before_last_move, empty, current]);
+    assert!(result.is_ok(), "Simple ko should not look further back than one move");
+
+    // Positional superko forbids it regardless of how far back it was
+    let result = play_ko_capture(Ruleset::Chinese, &[before_last_move, empty, current]);
+    assert!(result.unwrap_err().contains("positional superko"));
+}
+
+/// Tests that situational superko only forbids a repetition with the same player to move.
+#[test]
+fn test_situational_superko_considers_player_to_move() {
+    // The same board occurred, but with White to move: allowed situationally
+    let other_player = position_key(captured_hash(), &Occupant::White);
+    assert!(play_ko_capture(Ruleset::Aga, &[other_player]).is_ok());
+    assert!(play_ko_capture(Ruleset::NewZealand, &[other_player]).is_ok());
+    assert!(play_ko_capture(Ruleset::Chinese, &[other_player]).is_err());
+
+    // The same board with Black to move: rejected
+    let same_player = position_key(captured_hash(), &Occupant::Black);
+    let result = play_ko_capture(Ruleset::Aga, &[same_player]);
+    assert!(result.unwrap_err().contains("situational superko"));
+}
+
+/// Tests the scoring method, komi and handicap compensation each ruleset implies.
+#[test]
+fn test_ruleset_scoring_parameters() {
+    assert_eq!(Ruleset::Chinese.scoring_method(), ScoringMethod::Area);
+    assert_eq!(Ruleset::Japanese.scoring_method(), ScoringMethod::Territory);
+    assert_eq!(Ruleset::Korean.scoring_method(), ScoringMethod::Territory);
+    assert_eq!(Ruleset::TrompTaylor.scoring_method(), ScoringMethod::TrompTaylor);
+
+    assert_eq!(Ruleset::Japanese.default_komi(0, 19), 6.5);
+    assert_eq!(Ruleset::Chinese.default_komi(0, 19), 7.5);
+    assert_eq!(Ruleset::NewZealand.default_komi(0, 19), 7.0);
+    assert_eq!(Ruleset::Chinese.default_komi(4, 19), 0.5);
+
+    // Small boards use integer komi under area scoring
+    assert_eq!(Ruleset::Chinese.default_komi(0, 9), 7.0);
+    assert_eq!(Ruleset::Japanese.default_komi(0, 9), 6.5);
+    assert_eq!(Ruleset::Aga.default_komi(2, 9), 0.5);
+
+    assert_eq!(Ruleset::Chinese.handicap_compensation(4), 4.0);
+    assert_eq!(Ruleset::Aga.handicap_compensation(4), 3.0);
+    assert_eq!(Ruleset::Japanese.handicap_compensation(4), 0.0);
+    assert_eq!(Ruleset::Chinese.handicap_compensation(0), 0.0);
+}
+
+/// Tests which komi values a game can be created with.
+#[test]
+fn test_validate_komi() {
+    for komi in [6.5, 7.0, 0.0, 0.5, -5.5, -7.0] {
+        assert!(validate_komi(komi, 19).is_ok(), "{} should be allowed", komi);
+    }
+    assert!(validate_komi(6.25, 19).is_err());
+    assert!(validate_komi(f32::NAN, 19).is_err());
+    assert!(validate_komi(90.0, 9).is_err());
+    assert!(validate_komi(-81.0, 9).is_ok());
+}
@@ -0,0 +1,106 @@
+use crate::models::{Occupant, Point, ScoringMethod};
+use crate::scoring::{determine_territory, find_seki};
+use crate::tests::test_utils::create_board_from_string;
+
+/// Two groups in seki along the top edge, each with one eye and sharing the liberty at
+/// (0, 2). Either player filling it would leave their own group in atari.
+const ONE_EYE_SEKI: &str = "
+    .W.B.
+    WWBBB
+    BBWWW
+    .....
+    .....
+";
+
+/// Tests finding the chains, shared liberty and eyes of a seki.
+#[test]
+fn test_find_seki() {
+    let board = create_board_from_string(ONE_EYE_SEKI, 5);
+    let seki = find_seki(&board);
+
+    assert_eq!(seki.chains.len(), 2);
+    assert!(seki.chains.iter().any(|c| c.occupant == Occupant::White && c.stones.len() == 3));
+    assert!(seki.chains.iter().any(|c| c.occupant == Occupant::Black && c.stones.len() == 4));
+    assert_eq!(seki.shared_liberties, vec![Point::new(0, 2)]);
+    assert_eq!(seki.eyes.len(), 2);
+    assert!(seki.contains(Point::new(0, 0)));
+    assert!(seki.contains(Point::new(0, 4)));
+    assert!(!seki.contains(Point::new(3, 0)));
+}
+
+/// Tests that eyes in seki are territory under area scoring only.
+#[test]
+fn test_seki_eyes_by_scoring_method() {
+    let board = create_board_from_string(ONE_EYE_SEKI, 5);
+    assert_eq!(determine_territory(&board, ScoringMethod::Territory), (0, 0));
+    assert_eq!(determine_territory(&board, ScoringMethod::Area), (1, 1));
+
+    let mut annotated = board.clone();
+    annotated.annotate_for_scoring(ScoringMethod::Territory);
+    let shared = annotated.get(Point::new(0, 2)).unwrap();
+    assert_eq!(shared.scoring_owner, None);
+    assert_eq!(shared.scoring_explanation.as_deref(), Some("Seki (shared liberty)"));
+    let eye = annotated.get(Point::new(0, 0)).unwrap();
+    assert_eq!(eye.scoring_owner, None);
+    assert_eq!(eye.scoring_explanation.as_deref(), Some("Seki (eye)"));
+
+    annotated.annotate_for_scoring(ScoringMethod::Area);
+    let eye = annotated.get(Point::new(0, 0)).unwrap();
+    assert_eq!(eye.scoring_owner, Some(Occupant::White));
+    assert_eq!(eye.scoring_explanation.as_deref(), Some("Seki (eye)"));
+}
+
+/// Tests that a capturing race is not mistaken for seki: with an extra outside
+/// liberty, White can fill the shared liberty and capture Black.
+#[test]
+fn test_capturing_race_is_not_seki() {
+    let board = create_board_from_string(
+        "
+        .W.B.
+        WWBBB
+        B.WWW
+        .....
+        .....
+        ",
+        5,
+    );
+    let seki = find_seki(&board);
+    assert!(seki.chains.is_empty());
+    assert!(seki.shared_liberties.is_empty());
+    assert_eq!(determine_territory(&board, ScoringMethod::Territory), (1, 1));
+}
+
+/// Tests that territory next to a group in seki is not taken for its eye: the lone
+/// Black stone at (0, 3) is in seki, but the region beside it is enclosed by the
+/// Black wall as well.
+#[test]
+fn test_territory_beside_seki() {
+    let board = create_board_from_string(
+        "
+        .W.B...
+        WWWWB..
+        BBBBBBB
+        .......
+        .......
+        .......
+        .......
+        ",
+        7,
+    );
+    let seki = find_seki(&board);
+    assert_eq!(seki.chains.len(), 2);
+    assert_eq!(seki.shared_liberties, vec![Point::new(0, 2)]);
+    assert_eq!(seki.eyes.len(), 1);
+    assert!(seki.contains(Point::new(0, 0)));
+    assert!(!seki.contains(Point::new(0, 4)));
+
+    // The 5 points beside the seki and the 28 below the wall are Black's
+    assert_eq!(determine_territory(&board, ScoringMethod::Territory), (33, 0));
+    assert_eq!(determine_territory(&board, ScoringMethod::Area), (33, 1));
+
+    let mut annotated = board.clone();
+    annotated.annotate_for_scoring(ScoringMethod::Territory);
+    let beside = annotated.get(Point::new(0, 4)).unwrap();
+    assert_eq!(beside.scoring_owner, Some(Occupant::Black));
+    assert_eq!(beside.scoring_explanation.as_deref(), Some("Cell enclosed by Black"));
+}
@@ -0,0 +1,393 @@
+use crate::models::{GameResult, Occupant, Point, ResultReason, Ruleset, TimeControl};
+use crate::sgf::{
+    parse_sgf, read_sgf, write_sgf, SgfComment, SgfGame, SgfMarkup, SgfMove, SgfSetup,
+    SgfVariation, MAX_VARIATION_DEPTH,
+};
+
+/// Builds a short untimed 9x9 game record with no handicap.
+fn sample_game() -> SgfGame {
+    SgfGame {
+        board_size: 9,
+        komi: 7.0,
+        ruleset: Ruleset::Chinese,
+        handicap: 0,
+        handicap_stones: Vec::new(),
+        setup_white: Vec::new(),
+        first_player: None,
+        player_black: Some("Alice".to_string()),
+        player_white: Some("Bob".to_string()),
+        date: Some("2025-03-14".to_string()),
+        time_control: None,
+        result: None,
+        moves: vec![
+            SgfMove {
+                color: Occupant::Black,
+                point: Some(Point::new(2, 6)),
+                played_at: Some("2025-03-14T10:00:00+00:00".to_string()),
+            },
+            SgfMove {
+                color: Occupant::White,
+                point: None,
+                played_at: None,
+            },
+        ],
+        setup: Vec::new(),
+        comments: Vec::new(),
+        markup: Vec::new(),
+        variations: Vec::new(),
+    }
+}
+
+/// Tests writing the game information and the moves.
+#[test]
+fn test_write_sgf() {
+    let sgf = write_sgf(&sample_game());
+    assert_eq!(
+        sgf,
+        "(;FF[4]GM[1]CA[UTF-8]AP[GoTime]SZ[9]KM[7]RU[Chinese]PB[Alice]PW[Bob]DT[2025-03-14]\n\
+         ;B[gc]TS[2025-03-14T10:00:00+00:00]\n\
+         ;W[]\n\
+         )\n"
+    );
+}
+
+/// Tests the handicap setup, time settings and result.
+#[test]
+fn test_write_sgf_game_info() {
+    let mut game = sample_game();
+    game.ruleset = Ruleset::Japanese;
+    game.komi = 0.5;
+    game.handicap = 2;
+    game.handicap_stones = vec![Point::new(6, 2), Point::new(2, 6)];
+    game.first_player = Some(Occupant::White);
+    game.time_control = Some(TimeControl::ByoYomi {
+        main_time_ms: 600_000,
+        period_ms: 30_000,
+        periods: 5,
+    });
+    game.result = Some(GameResult::win_by(Occupant::White, ResultReason::Resignation));
+    game.moves.clear();
+
+    let sgf = write_sgf(&game);
+    assert!(sgf.starts_with("(;FF[4]GM[1]CA[UTF-8]AP[GoTime]SZ[9]KM[0.5]RU[Japanese]HA[2]"));
+    assert!(sgf.contains("TM[600]OT[5x30 byo-yomi]"));
+    assert!(sgf.contains("RE[W+R]"));
+    assert!(sgf.contains("AB[cg][gc]PL[W]"));
+
+    game.time_control = Some(TimeControl::Fischer {
+        main_time_ms: 300_000,
+        increment_ms: 2_500,
+    });
+    assert!(write_sgf(&game).contains("TM[300]OT[2.5 fischer]"));
+
+    game.setup_white = vec![Point::new(4, 4)];
+    assert!(write_sgf(&game).contains("AB[cg][gc]AW[ee]PL[W]"));
+}
+
+/// Tests that player names are escaped.
+#[test]
+fn test_write_sgf_escapes_text() {
+    let mut game = sample_game();
+    game.player_black = Some("[a]\\b".to_string());
+    assert!(write_sgf(&game).contains("PB[[a\\]\\\\b]"));
+}
+
+/// Tests that an exported game reads back as the same record.
+#[test]
+fn test_sgf_round_trip() {
+    let mut game = sample_game();
+    game.ruleset = Ruleset::Aga;
+    game.komi = 7.5;
+    game.handicap = 2;
+    game.handicap_stones = vec![Point::new(6, 2), Point::new(2, 6)];
+    game.setup_white = vec![Point::new(4, 4)];
+    game.first_player = Some(Occupant::White);
+    game.player_white = Some("Bob [5k]".to_string());
+    game.result = Some(GameResult::from_score(40.0, 43.5));
+    assert_eq!(read_sgf(&write_sgf(&game)), Ok(game.clone()));
+
+    game.setup = vec![
+        SgfSetup {
+            before_move: 0,
+            empty: vec![Point::new(4, 4)],
+            ..SgfSetup::default()
+        },
+        SgfSetup {
+            before_move: 1,
+            black: vec![Point::new(0, 0)],
+            white: vec![Point::new(8, 8), Point::new(7, 8)],
+            empty: vec![Point::new(2, 6)],
+        },
+        SgfSetup {
+            before_move: 2,
+            white: vec![Point::new(1, 1)],
+            ..SgfSetup::default()
+        },
+    ];
+    assert_eq!(read_sgf(&write_sgf(&game)), Ok(game));
+}
+
+/// Tests writing stones added and removed between moves as nodes of their own.
+#[test]
+fn test_write_sgf_setup() {
+    let mut game = sample_game();
+    game.setup = vec![
+        SgfSetup {
+            before_move: 1,
+            black: vec![Point::new(0, 0)],
+            empty: vec![Point::new(2, 6)],
+            ..SgfSetup::default()
+        },
+        SgfSetup {
+            before_move: 2,
+            white: vec![Point::new(1, 1)],
+            ..SgfSetup::default()
+        },
+    ];
+    assert!(write_sgf(&game).ends_with(
+        ";B[gc]TS[2025-03-14T10:00:00+00:00]\n\
+         ;AB[aa]AE[gc]\n\
+         ;W[]\n\
+         ;AW[bb]\n\
+         )\n"
+    ));
+}
+
+/// Tests parsing the game tree, including variations, comments and escapes.
+#[test]
+fn test_parse_sgf_tree() {
+    let tree = parse_sgf(
+        r"(;GM[1]FF[4]SZ[13]C[A comment with \\ and \] in it]
+          ;B[dd]C[Soft\
+line break]
+          (;W[jj];B[jd]TR[aa][bb])
+          (;W[dj]LB[cc:A])
+         )",
+    )
+    .unwrap();
+    assert_eq!(tree.board_size, 13);
+    assert_eq!(tree.root().get("C"), Some("A comment with \\ and ] in it"));
+
+    let main_line = tree.main_line();
+    assert_eq!(main_line.len(), 4);
+    assert_eq!(main_line[1].get("C"), Some("Softline break"));
+    assert_eq!(tree.tree.nodes.len(), 2);
+    assert_eq!(tree.tree.variations.len(), 2);
+    assert_eq!(main_line[3].values("TR"), ["aa", "bb"]);
+    assert_eq!(tree.tree.variations[1].nodes[0].get("W"), Some("dj"));
+}
+
+/// Tests reading setup stones, passes and the first player from a record.
+/// Tests that comments, markup and variations off the main line are read with the
+/// move they follow, and written back out.
+#[test]
+fn test_read_sgf_annotations() {
+    let game = read_sgf(
+        r"(;SZ[9]C[Start]TR[aa]
+          ;B[ee]C[Good \] move]LB[ee:A][ff:B]
+          (;W[ce];AE[ee]C[Cleared](;B[dd])(;B[gg]C[Other]))
+          (;W[gc];B[ce])
+          (;W[cc]))",
+    )
+    .unwrap();
+    assert_eq!(game.moves.len(), 3);
+    assert_eq!(
+        game.comments,
+        [
+            SgfComment {
+                move_number: 0,
+                text: "Start".to_string(),
+            },
+            SgfComment {
+                move_number: 1,
+                text: "Good ] move".to_string(),
+            },
+            SgfComment {
+                move_number: 2,
+                text: "Cleared".to_string(),
+            },
+        ]
+    );
+    assert_eq!(
+        game.markup,
+        [
+            SgfMarkup {
+                move_number: 0,
+                shape: "TR".to_string(),
+                point: Point::new(0, 0),
+                label: None,
+            },
+            SgfMarkup {
+                move_number: 1,
+                shape: "LB".to_string(),
+                point: Point::new(4, 4),
+                label: Some("A".to_string()),
+            },
+            SgfMarkup {
+                move_number: 1,
+                shape: "LB".to_string(),
+                point: Point::new(5, 5),
+                label: Some("B".to_string()),
+            },
+        ]
+    );
+    assert_eq!(
+        game.variations,
+        [
+            SgfVariation {
+                move_number: 1,
+                sgf: "(;W[gc];B[ce])".to_string(),
+            },
+            SgfVariation {
+                move_number: 1,
+                sgf: "(;W[cc])".to_string(),
+            },
+            SgfVariation {
+                move_number: 2,
+                sgf: "(;B[gg]C[Other])".to_string(),
+            },
+        ]
+    );
+
+    // The comment on the setup node moves to the move before it
+    let sgf = write_sgf(&game);
+    assert!(sgf.contains(";B[ee]C[Good \\] move]LB[ee:A][ff:B]"), "{}", sgf);
+    assert!(sgf.contains(";W[ce]C[Cleared]"), "{}", sgf);
+    let again = read_sgf(&sgf).unwrap();
+    assert_eq!(again.moves, game.moves);
+    assert_eq!(again.setup, game.setup);
+    assert_eq!(again.comments, game.comments);
+    assert_eq!(again.markup, game.markup);
+    assert_eq!(again.variations, game.variations);
+}
+
+/// Tests that variations branching off after the last move are written after an
+/// empty node standing for the end of the main line.
+#[test]
+fn test_write_sgf_variations_after_last_move() {
+    let mut game = sample_game();
+    game.variations.push(SgfVariation {
+        move_number: 2,
+        sgf: "(;B[aa])".to_string(),
+    });
+    let sgf = write_sgf(&game);
+    assert!(sgf.ends_with("(;)\n(;B[aa])\n)\n"), "{}", sgf);
+    assert_eq!(read_sgf(&sgf).unwrap().variations, game.variations);
+}
+
+/// Tests that long main lines and game trees nested one inside another are joined
+/// into a single sequence, while variations nested too deep are rejected.
+#[test]
+fn test_parse_sgf_nesting() {
+    let moves: String = (0..20_000).map(|i| if i % 2 == 0 { ";B[]" } else { ";W[]" }).collect();
+    let tree = parse_sgf(&format!("(;SZ[9]{})", moves)).unwrap();
+    assert_eq!(tree.main_line().len(), 20_001);
+
+    let nested = format!("{}{}", "(;B[]".repeat(20_000), ")".repeat(20_000));
+    let tree = parse_sgf(&nested).unwrap();
+    assert_eq!(tree.tree.nodes.len(), 20_000);
+    assert!(tree.tree.variations.is_empty());
+
+    // Each branch point nests the main line one game tree deeper
+    let branches = |points: usize| {
+        format!("{}(;W[]){}", "(;B[]".repeat(points), "(;W[]))".repeat(points))
+    };
+    let tree = parse_sgf(&branches(MAX_VARIATION_DEPTH - 1)).unwrap();
+    assert_eq!(tree.main_line().len(), MAX_VARIATION_DEPTH);
+    assert!(parse_sgf(&branches(MAX_VARIATION_DEPTH))
+        .unwrap_err()
+        .contains("nested"));
+}
+
+#[test]
+fn test_read_sgf_game() {
+    let game = read_sgf(
+        "(;SZ[9]KM[6.5]RU[Japanese]HA[2]AB[cc:cd]AW[gg]PL[W]PB[Alice]RE[B+R]
+          ;W[ee];B[];W[tt])",
+    )
+    .unwrap();
+    assert_eq!(game.board_size, 9);
+    assert_eq!(game.komi, 6.5);
+    assert_eq!(game.ruleset, Ruleset::Japanese);
+    assert_eq!(game.handicap, 2);
+    assert_eq!(game.handicap_stones, [Point::new(2, 2), Point::new(3, 2)]);
+    assert_eq!(game.setup_white, [Point::new(6, 6)]);
+    assert_eq!(game.first_player, Some(Occupant::White));
+    assert_eq!(game.player_black.as_deref(), Some("Alice"));
+    assert_eq!(
+        game.result,
+        Some(GameResult::win_by(Occupant::Black, ResultReason::Resignation))
+    );
+
+    let moves: Vec<_> = game.moves.iter().map(|mv| (mv.color.clone(), mv.point)).collect();
+    assert_eq!(
+        moves,
+        [
+            (Occupant::White, Some(Point::new(4, 4))),
+            (Occupant::Black, None),
+            (Occupant::White, None),
+        ]
+    );
+
+    assert!(game.setup.is_empty());
+
+    // Missing game information falls back to SGF's defaults
+    let game = read_sgf("(;RU[Unknown];B[pd])").unwrap();
+    assert_eq!(game.board_size, 19);
+    assert_eq!(game.komi, 0.0);
+    assert_eq!(game.ruleset, Ruleset::default());
+}
+
+/// Tests reading stones added and removed after the starting position.
+#[test]
+fn test_read_sgf_setup() {
+    let game = read_sgf("(;SZ[9]AB[aa]AE[bb];B[cc];AW[dd:de]AE[cc];W[ee]AB[ff])").unwrap();
+    assert_eq!(game.handicap_stones, [Point::new(0, 0)]);
+    assert_eq!(
+        game.setup,
+        [
+            SgfSetup {
+                before_move: 0,
+                empty: vec![Point::new(1, 1)],
+                ..SgfSetup::default()
+            },
+            SgfSetup {
+                before_move: 1,
+                white: vec![Point::new(3, 3), Point::new(4, 3)],
+                empty: vec![Point::new(2, 2)],
+                ..SgfSetup::default()
+            },
+            // Setup in a node with a move comes before the move
+            SgfSetup {
+                before_move: 1,
+                black: vec![Point::new(5, 5)],
+                ..SgfSetup::default()
+            },
+        ]
+    );
+    assert_eq!(game.moves.len(), 2);
+}
+
+/// Tests that malformed and invalid records are rejected.
+#[test]
+fn test_parse_sgf_errors() {
+    assert!(parse_sgf("").is_err());
+    assert!(parse_sgf("(;SZ[9]").is_err());
+    assert!(parse_sgf("(;C[unterminated)").is_err());
+    assert!(parse_sgf("(;GM[2])").unwrap_err().contains("Not a game of Go"));
+    assert!(parse_sgf("(;SZ[19:13])").unwrap_err().contains("square"));
+    assert!(parse_sgf("(;SZ[30])").is_err());
+    assert!(parse_sgf("(;SZ[9];B[jj])").is_err());
+    assert!(parse_sgf("(;SZ[9]AB[aa:jj])").unwrap_err().contains("AB"));
+    assert!(parse_sgf("(;SZ[9];B[aa]W[bb])").is_err());
+    assert!(parse_sgf("(;SZ[9];B[aa](;W[zz])(;W[bb]))").is_err());
+
+    assert!(parse_sgf("(;SZ[9]TR[zz])").unwrap_err().contains("TR"));
+    assert!(parse_sgf("(;SZ[9]LB[zz:A])").unwrap_err().contains("LB"));
+    assert!(parse_sgf("(;SZ[9]LB[aa])").is_err());
+    assert!(parse_sgf("(;SZ[9]AR[aa:zz])").is_err());
+    assert!(parse_sgf("(;SZ[9];B[aa](;W[bb])(;W[cc]SQ[zz]))").is_err());
+
+    assert!(parse_sgf("(;SZ[9];AE[jj])").unwrap_err().contains("AE"));
+    assert!(read_sgf("(;KM[seven])").is_err());
+}
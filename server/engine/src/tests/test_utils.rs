@@ -1,4 +1,4 @@
-use crate::models::{Board, Occupant, SpotState};
+use crate::models::{Board, Occupant, Point, SpotState};
 use serde_json;
 
 /// Creates an empty board with the specified size.
@@ -19,6 +19,7 @@ pub fn create_empty_board(size: u8) -> Board {
             marker: None,
             scoring_owner: None,
             scoring_explanation: None,
+            group_status: None,
             playable: true,
         };
         (size as usize).pow(2)
@@ -34,11 +35,11 @@ pub fn create_empty_board(size: u8) -> Board {
 /// * `col` - The column coordinate (0-based)
 /// * `occupant` - The type of stone to place (Black, White, or Empty)
 pub fn place_test_stone(board: &mut Board, row: u8, col: u8, occupant: Occupant) {
-    if let Some(spot) = board.get_mut(row, col) {
-        spot.occupant = occupant;
-    } else {
+    let point = Point::new(row, col);
+    if !point.is_on_board(board.board_size) {
         panic!("Attempted to place stone at invalid position ({}, {})", row, col);
     }
+    board.set_occupant(point, occupant);
 }
 
 /// Serializes a board to a JSON string.
@@ -86,6 +87,7 @@ pub fn create_board_from_vec(vec: Vec<Occupant>, board_size: u8) -> Board {
             marker: None,
             scoring_owner: None,
             scoring_explanation: None,
+            group_status: None,
             playable: true,
         })
         .collect();
@@ -149,6 +151,7 @@ pub fn create_board_from_string(board_str: &str, board_size: u8) -> Board {
             marker: None,
             scoring_owner: None,
             scoring_explanation: None,
+            group_status: None,
             playable: true,
         })
         .collect();
@@ -0,0 +1,131 @@
+use crate::models::{validate_board_size, Board, Occupant, Point, Ruleset, MAX_BOARD_SIZE};
+use crate::tests::test_utils::{create_board_from_string, create_empty_board};
+use crate::utils::{apply_move_to_board, get_group_indices, position_hash, remove_group};
+
+/// Tests that placing stones through the board keeps the hash equal to a full recomputation.
+#[test]
+fn test_incremental_hash_matches_recomputed_hash() {
+    let mut board = create_empty_board(9);
+    board.set_occupant(Point::new(2, 3), Occupant::Black);
+    board.set_occupant(Point::new(4, 4), Occupant::White);
+    board.set_occupant(Point::new(2, 3), Occupant::White);
+
+    let expected = create_board_from_string(
+        "
+        .........
+        .........
+        ...W.....
+        .........
+        ....W....
+        .........
+        .........
+        .........
+        .........
+        ",
+        9,
+    );
+    assert_eq!(board.position_hash(), expected.position_hash());
+}
+
+/// Tests that removing a captured group restores the hash of the board without it.
+#[test]
+fn test_remove_group_updates_hash() {
+    let mut board = create_board_from_string(
+        "
+        .....
+        .BB..
+        .....
+        .....
+        .....
+        ",
+        5,
+    );
+    let empty_hash = create_empty_board(5).position_hash();
+    assert_ne!(board.position_hash(), empty_hash);
+
+    let group = get_group_indices(&board.spots, 5, Point::new(1, 1));
+    remove_group(&mut board, &group);
+
+    assert_eq!(board.position_hash(), empty_hash, "Hash should match the empty board");
+    assert_eq!(board.spots[6].marker.as_deref(), Some("captured"));
+}
+
+/// Tests that the hash returned by the move path matches the board's own hash.
+#[test]
+fn test_move_result_hash_matches_board_hash() {
+    let board = create_board_from_string(
+        "
+        .B...
+        BW...
+        .B...
+        .....
+        .....
+        ",
+        5,
+    );
+    let (new_board, _, _) =
+        apply_move_to_board(board.spots, 5, Occupant::Black, Point::new(1, 2), &[], Ruleset::Chinese, 1)
+            .expect("Capturing move should be legal");
+
+    let rebuilt = Board::new(new_board.clone(), 5);
+    assert_eq!(position_hash(&new_board, 5), rebuilt.position_hash());
+}
+
+/// Tests that empty boards of different sizes hash differently.
+#[test]
+fn test_hash_includes_board_size() {
+    assert_ne!(
+        create_empty_board(9).position_hash(),
+        create_empty_board(13).position_hash()
+    );
+}
+
+/// Tests that the canonical hash is shared by rotations and reflections of a position.
+#[test]
+fn test_canonical_hash_is_symmetry_invariant() {
+    let original = create_board_from_string(
+        "
+        B....
+        .W...
+        .....
+        .....
+        ....W
+        ",
+        5,
+    );
+    let rotated = create_board_from_string(
+        "
+        ....B
+        ...W.
+        .....
+        .....
+        W....
+        ",
+        5,
+    );
+    let different = create_board_from_string(
+        "
+        B....
+        ..W..
+        .....
+        .....
+        ....W
+        ",
+        5,
+    );
+
+    assert_ne!(original.position_hash(), rotated.position_hash());
+    assert_eq!(original.canonical_hash(), rotated.canonical_hash());
+    assert_ne!(original.canonical_hash(), different.canonical_hash());
+}
+
+/// Tests that only sizes the Zobrist keys cover are accepted.
+#[test]
+fn test_validate_board_size() {
+    for size in [1, 9, 19, MAX_BOARD_SIZE] {
+        assert!(validate_board_size(size).is_ok(), "{} should be allowed", size);
+    }
+    for size in [0, MAX_BOARD_SIZE + 1, u8::MAX] {
+        assert!(validate_board_size(size).is_err(), "{} should be rejected", size);
+    }
+}
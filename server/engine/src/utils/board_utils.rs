@@ -0,0 +1,345 @@
+use crate::models::{hash_spots, Board, Chains, MoveEffect, Occupant, Point, Ruleset, SpotState};
+use serde_json;
+use std::collections::{HashSet, VecDeque};
+
+/// Finds all positions that are part of the same connected group as the given position.
+///
+/// A group is a connected set of stones of the same color. In Go, stones are 
+/// connected if they are adjacent horizontally or vertically (not diagonally).
+///
+/// # Arguments
+/// * `board` - The current board state
+/// * `board_size` - The size of the board
+/// * `start` - The starting position
+///
+/// # Returns
+/// A HashSet containing the indices of all positions in the group
+pub fn get_group_indices(board: &[SpotState], board_size: u8, start: Point) -> HashSet<usize> {
+    let mut group = HashSet::new();
+    let mut queue = VecDeque::new();
+    
+    let start_index = start.index(board_size);
+    let target_occupant = &board[start_index].occupant;
+    
+    // Handle empty spaces - they don't form groups in Go
+    if *target_occupant == Occupant::Empty {
+        group.insert(start_index);
+        return group;
+    }
+    
+    group.insert(start_index);
+    queue.push_back(start);
+    
+    // Breadth-first search to find all connected stones of the same color
+    while let Some(current) = queue.pop_front() {
+        for neighbor in current.neighbors(board_size) {
+            let n_index = neighbor.index(board_size);
+            
+            if !group.contains(&n_index) && board[n_index].occupant == *target_occupant {
+                group.insert(n_index);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    
+    group
+}
+
+/// Checks if a group of stones has at least one liberty (empty adjacent point).
+///
+/// In Go, a liberty is an empty intersection adjacent to a stone. A group with
+/// no liberties is captured and removed from the board.
+///
+/// # Arguments
+/// * `board` - The current board state
+/// * `board_size` - The size of the board
+/// * `group` - A set of indices representing the group to check
+///
+/// # Returns
+/// `true` if the group has at least one liberty, `false` otherwise
+pub fn group_has_liberty(board: &[SpotState], board_size: u8, group: &HashSet<usize>) -> bool {
+    let size = board_size as usize;
+    for &idx in group {
+        let point = Point::new((idx / size) as u8, (idx % size) as u8);
+        
+        for neighbor in point.neighbors(board_size) {
+            if board[neighbor.index(board_size)].occupant == Occupant::Empty {
+                return true; // Found a liberty
+            }
+        }
+    }
+    
+    false // No liberties found
+}
+
+/// Removes a group of stones from the board by setting their spots to empty.
+///
+/// This is used when a group is captured (has no liberties). The board's position
+/// hash is updated incrementally as each stone is removed.
+///
+/// # Arguments
+/// * `board` - The board to modify
+/// * `group` - A set of indices representing the group to remove
+pub fn remove_group(board: &mut Board, group: &HashSet<usize>) {
+    let size = board.board_size as usize;
+    for &idx in group {
+        board.set_occupant(Point::new((idx / size) as u8, (idx % size) as u8), Occupant::Empty);
+        board.spots[idx].move_number = None;
+        board.spots[idx].marker = Some("captured".to_string()); // Mark as captured for UI
+    }
+}
+
+/// Computes the Zobrist hash identifying a whole-board position.
+///
+/// Only the stones on the board contribute to the hash; UI metadata such as
+/// markers, move numbers and scoring annotations are ignored, so two boards
+/// with the same stone placement always hash to the same value.
+///
+/// # Arguments
+/// * `board` - The board state to hash
+/// * `board_size` - The size of the board
+///
+/// # Returns
+/// The same value [`Board::position_hash`] reports for this board
+pub fn position_hash(board: &[SpotState], board_size: u8) -> u64 {
+    hash_spots(board, board_size)
+}
+
+/// Applies a move to the board and handles captures and rule enforcement.
+///
+/// This is a pure function that returns a new board state rather than modifying the input.
+/// It handles:
+/// - Placing a stone
+/// - Capturing opponent groups with no liberties
+/// - Checking for self_capture moves (illegal unless the ruleset allows suicide, in
+///   which case the player's own group is removed after captures are resolved)
+/// - Checking for ko and superko violations (illegal), according to the ruleset
+///
+/// Superko forbids any move that recreates a whole-board position that has occurred
+/// earlier in the game (situational superko additionally requires the same player to
+/// be on move). This covers basic ko as well as longer cycles such as triple ko or
+/// "sending two, returning one". Rulesets with the simple ko rule only forbid
+/// recreating the position from before the opponent's last move.
+///
+/// # Arguments
+/// * `board` - The current board state
+/// * `board_size` - The size of the board
+/// * `stone_color` - The color of the stone to place
+/// * `point` - Where to place the stone
+/// * `position_history` - Position keys (see [`crate::models::position_key`]) of every earlier
+///   position in the game
+/// * `ruleset` - The ruleset the game is played under
+/// * `move_number` - Sequential number of this move, recorded on the placed stone
+///
+/// # Returns
+/// * `Ok((new_board, serialized_board, effect))` - The updated board, its serialized form,
+///   and the stones the move captured (or removed by suicide), for prisoner counting
+/// * `Err(message)` - An error message if the move is illegal
+pub fn apply_move_to_board(
+    board: Vec<SpotState>,
+    board_size: u8,
+    stone_color: Occupant,
+    point: Point,
+    position_history: &[u64],
+    ruleset: Ruleset,
+    move_number: u64,
+) -> Result<(Vec<SpotState>, Option<String>, MoveEffect), String> {
+    if !point.is_on_board(board_size) {
+        return Err(format!(
+            "Move at (row {}, col {}) is outside the board",
+            point.row, point.col
+        ));
+    }
+    let mut board = Board::new(board, board_size);
+    let idx = point.index(board_size);
+    
+    // Print occupant for debugging
+    log::debug!("Occupant at index {}: {:?}", idx, board.spots[idx].occupant);
+    
+    // Check legality (occupancy, suicide and ko) against the board's chains
+    let mut chains = Chains::from_board(&board);
+    chains.check_move(idx, &stone_color, ruleset, position_history)?;
+    
+    // Place the stone, capturing opponent groups left without liberties and removing
+    // the player's own group if the move was a (legal) suicide
+    let effect = chains.play(idx, stone_color.clone());
+    board.set_occupant(point, stone_color);
+    board.spots[idx].move_number = Some(move_number);
+    
+    let removed: HashSet<usize> = effect.captured.iter().chain(&effect.suicided).copied().collect();
+    remove_group(&mut board, &removed);
+    
+    // Serialize the new board state for storage
+    let new_board_str = match serde_json::to_string(&board.spots) {
+        Ok(s) => s,
+        Err(e) => return Err(format!("Failed to serialize board: {}", e)),
+    };
+    
+    // Log the board state for debugging
+    for row in board.spots.chunks(board_size as usize) {
+        let mut row_str = String::new();
+        for spot in row {
+            let stone = match spot.occupant {
+                Occupant::Black => "B",
+                Occupant::White => "W",
+                Occupant::Empty => ".",
+            };
+            row_str.push_str(stone);
+        }
+        log::debug!("New {}", row_str);
+    }
+    
+    Ok((board.spots, Some(new_board_str), effect))
+}
+
+/// Places one of Black's handicap stones during free handicap placement.
+///
+/// Handicap stones are not moves: they may go on any empty point, and are numbered 0
+/// like the stones of a fixed handicap. A stone that would leave its own group without
+/// liberties (only possible on a very small board) is rejected.
+///
+/// # Arguments
+/// * `board` - The current board state
+/// * `board_size` - The size of the board
+/// * `point` - Where to place the stone
+///
+/// # Returns
+/// * `Ok(new_board)` - The board with the handicap stone added
+/// * `Err(message)` - If the point is off the board, occupied, or has no liberty
+pub fn place_handicap_stone(
+    board: Vec<SpotState>,
+    board_size: u8,
+    point: Point,
+) -> Result<Vec<SpotState>, String> {
+    place_setup_stone(board, board_size, Occupant::Black, point)
+}
+
+/// Places a stone of either color without a move, e.g. when setting up a position
+/// from an SGF file.
+///
+/// Like handicap stones, setup stones are numbered 0, may go on any empty point,
+/// and must leave their own group a liberty.
+///
+/// # Arguments
+/// * `board` - The current board state
+/// * `board_size` - The size of the board
+/// * `color` - The color of the stone
+/// * `point` - Where to place the stone
+///
+/// # Returns
+/// * `Ok(new_board)` - The board with the stone added
+/// * `Err(message)` - If the point is off the board, occupied, or has no liberty
+pub fn place_setup_stone(
+    mut board: Vec<SpotState>,
+    board_size: u8,
+    color: Occupant,
+    point: Point,
+) -> Result<Vec<SpotState>, String> {
+    if !point.is_on_board(board_size) {
+        return Err(format!(
+            "Stone at (row {}, col {}) is outside the board",
+            point.row, point.col
+        ));
+    }
+    let idx = point.index(board_size);
+    if board[idx].occupant != Occupant::Empty {
+        return Err(format!(
+            "There is already a stone at (row {}, col {})",
+            point.row, point.col
+        ));
+    }
+
+    board[idx].occupant = color;
+    board[idx].move_number = Some(0);
+    let group = get_group_indices(&board, board_size, point);
+    if !group_has_liberty(&board, board_size, &group) {
+        return Err("A setup stone must leave its group a liberty".to_string());
+    }
+    Ok(board)
+}
+
+/// Removes the stone on a point without a move, e.g. when an SGF file clears points
+/// while setting up a position. Clearing an empty point leaves the board unchanged.
+///
+/// # Arguments
+/// * `board` - The current board state
+/// * `board_size` - The size of the board
+/// * `point` - The point to clear
+///
+/// # Returns
+/// * `Ok(new_board)` - The board with the point empty
+/// * `Err(message)` - If the point is off the board
+pub fn remove_setup_stone(
+    mut board: Vec<SpotState>,
+    board_size: u8,
+    point: Point,
+) -> Result<Vec<SpotState>, String> {
+    if !point.is_on_board(board_size) {
+        return Err(format!(
+            "Point (row {}, col {}) is outside the board",
+            point.row, point.col
+        ));
+    }
+    let idx = point.index(board_size);
+    board[idx].occupant = Occupant::Empty;
+    board[idx].move_number = None;
+    Ok(board)
+}
+
+/// Returns the standard points for a fixed handicap, in the order GTP's
+/// `fixed_handicap` command places them.
+///
+/// The corner stones go on the 3-3 points on boards up to 12×12 and on the 4-4
+/// points on larger boards. Two stones take the lower left and upper right corners,
+/// the third and fourth fill the lower right and upper left, six stones add the
+/// left and right sides, and eight add the top and bottom. Odd counts from five
+/// upwards add the center stone. Only odd boards from 9×9 have side and center
+/// points, so even boards and 7×7 allow at most four stones.
+///
+/// # Arguments
+/// * `board_size` - Size of the board, from 7 to 25
+/// * `stones` - Number of handicap stones, from 2 to 9
+///
+/// # Returns
+/// * `Ok(points)` - Where to place the handicap stones
+/// * `Err(message)` - If the board size or stone count has no standard layout
+pub fn fixed_handicap_points(board_size: u8, stones: u8) -> Result<Vec<Point>, String> {
+    if !(7..=25).contains(&board_size) {
+        return Err(format!(
+            "Fixed handicap is not available on a {}x{} board",
+            board_size, board_size
+        ));
+    }
+    let max_stones = if board_size.is_multiple_of(2) || board_size == 7 { 4 } else { 9 };
+    if stones < 2 || stones > max_stones {
+        return Err(format!(
+            "Fixed handicap on a {}x{} board needs between 2 and {} stones",
+            board_size, board_size, max_stones
+        ));
+    }
+
+    let low = if board_size <= 12 { 2 } else { 3 };
+    let high = board_size - 1 - low;
+    let mid = board_size / 2;
+
+    // Lower left, upper right, lower right, upper left
+    let mut points = vec![
+        Point::new(high, low),
+        Point::new(low, high),
+        Point::new(high, high),
+        Point::new(low, low),
+    ];
+    points.truncate(stones.min(4) as usize);
+    if stones >= 6 {
+        points.push(Point::new(mid, low));
+        points.push(Point::new(mid, high));
+    }
+    if stones >= 8 {
+        points.push(Point::new(high, mid));
+        points.push(Point::new(low, mid));
+    }
+    if stones >= 5 && !stones.is_multiple_of(2) {
+        points.push(Point::new(mid, mid));
+    }
+    Ok(points)
+}
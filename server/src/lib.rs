@@ -4,18 +4,18 @@
  * A Rust implementation of the board game Go (also known as Baduk or Weiqi)
  * built on SpacetimeDB.
  *
- * This crate provides:
- * - Game rules enforcement
- * - Board state tracking and analysis
- * - Scoring and territory calculation
- * - User and game management
+ * The rules themselves live in the `go-engine` crate; this crate provides:
+ * - Database tables for users, messages, and games
+ * - Reducers for user and game management
+ * - Game analysis and scoring on every update
+ * - SGF export and import of games
+ * - Sample game seeding
  */
 
 pub mod models;
 pub mod reducers;
 pub mod scoring;
 pub mod seed;
-pub mod utils;
 
 #[cfg(test)]
 pub mod tests;
@@ -25,8 +25,3 @@ pub use models::*;
 pub use reducers::*;
 pub use scoring::*;
 pub use seed::*;
-pub use utils::*;
-
-// Export test modules for testing
-#[cfg(test)]
-pub use tests::*;
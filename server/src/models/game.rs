@@ -1,5 +1,11 @@
-use super::board::Board;
-use spacetimedb::{table, Identity, Timestamp};
+use crate::reducers::{check_clock, play_bot_turn};
+use go_engine::bot::BotLevel;
+use go_engine::models::{
+    position_key, Board, Clock, GameResult, Occupant, Point, Ruleset, TimeControl,
+};
+use go_engine::sgf::{SgfComment, SgfGame, SgfMarkup, SgfMove, SgfSetup, SgfVariation};
+use go_engine::utils::{apply_move_to_board, position_hash};
+use spacetimedb::{table, Identity, ScheduleAt, Timestamp};
 
 /// Represents a user in the Go game system.
 #[table(name = user, public)]
@@ -53,20 +59,324 @@ pub struct Game {
     /// Size of the board (typically 9x9, 13x13, or 19x19)
     pub board_size: u8,
     
-    /// Previous board state for ko rule checking
-    pub previous_board: Option<String>,
+    /// Position keys (board hash combined with the player to move) of every position
+    /// reached so far, used for ko and superko checking
+    pub position_history: Vec<u64>,
+
+    /// Identifier of the ruleset this game is played under (see [`Ruleset::as_str`])
+    pub ruleset: String,
+
+    /// Number of handicap stones Black received
+    pub handicap: u8,
+
+    /// Handicap stones Black still has to place before White's first move, in a game
+    /// with free handicap placement
+    pub handicap_pending: u8,
+
+    /// Points added to White's score; negative for reverse komi. Handicap compensation
+    /// under the game's ruleset is added on top when scoring
+    pub komi: f32,
+
+    /// Number of White stones Black has captured (prisoners held by Black)
+    pub captures_black: u32,
+
+    /// Number of Black stones White has captured (prisoners held by White)
+    pub captures_white: u32,
+
+    /// Number of moves (including passes) played so far; handicap stones are not counted
+    pub move_count: u32,
     
     /// Whether the game has concluded
     pub game_over: bool,
+
+    /// Whether both players have passed and are agreeing on which stones are dead
+    pub scoring_phase: bool,
+
+    /// GTP vertices of the stones marked dead during the scoring phase
+    pub dead_stones: Vec<String>,
+
+    /// Whether Black has accepted the score shown in the scoring phase
+    pub accepted_black: bool,
+
+    /// Whether White has accepted the score shown in the scoring phase
+    pub accepted_white: bool,
     
     /// Final score for the Black player
     pub final_score_black: Option<f32>,
     
     /// Final score for the White player
     pub final_score_white: Option<f32>,
+
+    /// Winner of a finished game: "B" for Black, "W" for White, or None for a jigo
+    pub winner: Option<String>,
+
+    /// How a finished game was decided: "score", "resignation", "timeout", "forfeit" or "jigo"
+    pub result_reason: Option<String>,
+
+    /// Points the winner won by, for games decided by counting
+    pub result_margin: Option<f32>,
+
+    /// Result of a finished game in standard notation, e.g. "B+R" or "W+3.5"
+    pub result: Option<String>,
+
+    /// JSON-serialized time control (see [`TimeControl`]), or None for an untimed game
+    pub time_control: Option<String>,
+
+    /// JSON-serialized clock of the Black player, for timed games
+    pub clock_black: Option<String>,
+
+    /// JSON-serialized clock of the White player, for timed games
+    pub clock_white: Option<String>,
+
+    /// When the player to move started their turn; None until both players have joined
+    pub turn_started_at: Option<Timestamp>,
+
+    /// Difficulty level of the built-in bot (see `go_engine::bot::BotLevel`), for
+    /// games against it
+    pub bot_level: Option<String>,
+}
+
+/// Represents one entry in a game's move record.
+///
+/// Every stone placement, pass and handicap stone is recorded, so a game can be
+/// replayed, exported or audited from its moves alone.
+#[table(name = game_move, public)]
+pub struct GameMove {
+    /// Unique identifier for the record entry
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    /// The game this move belongs to
+    #[index(btree)]
+    pub game_id: u64,
+
+    /// Sequential move number starting at 1; handicap and setup stones are numbered 0
+    pub move_number: u32,
+
+    /// Color of the player who moved: "B" for Black or "W" for White; for a cleared
+    /// point, the color of the stone removed
+    pub color: String,
+
+    /// What happened: "play", "pass", "handicap", "setup", "clear" or "resign"; setup
+    /// stones are added and points cleared to set up the position of a game imported
+    /// from SGF
+    pub action: String,
+
+    /// GTP vertex of the stone (e.g. "D4"), or None for a pass
+    pub point: Option<String>,
+
+    /// Number of opponent stones captured by the move
+    pub captured: u32,
+
+    /// When the move was played
+    pub played_at: Timestamp,
+}
+
+/// A comment on a position of a game imported from SGF.
+#[table(name = game_comment, public)]
+pub struct GameComment {
+    /// Unique identifier for the comment
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    /// The game the comment belongs to
+    #[index(btree)]
+    pub game_id: u64,
+
+    /// Number of moves played when the comment was made; 0 for the starting position
+    pub move_number: u32,
+
+    /// The comment text
+    pub text: String,
+}
+
+/// A mark drawn on a point of a game imported from SGF, such as a triangle or a
+/// label.
+#[table(name = game_markup, public)]
+pub struct GameMarkup {
+    /// Unique identifier for the mark
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    /// The game the mark belongs to
+    #[index(btree)]
+    pub game_id: u64,
+
+    /// Number of moves played when the mark was drawn; 0 for the starting position
+    pub move_number: u32,
+
+    /// The SGF markup property: "CR" (circle), "SQ" (square), "TR" (triangle), "MA"
+    /// (cross) or "LB" (label)
+    pub shape: String,
+
+    /// GTP vertex of the marked point (e.g. "D4")
+    pub point: String,
+
+    /// The text of a label
+    pub label: Option<String>,
+}
+
+/// A line of play branching off the main line of a game imported from SGF.
+///
+/// Variations are kept as SGF text rather than replayed, so they can be exported
+/// with the game again.
+#[table(name = game_variation, public)]
+pub struct GameVariation {
+    /// Unique identifier for the variation
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    /// The game the variation belongs to
+    #[index(btree)]
+    pub game_id: u64,
+
+    /// Number of main-line moves played before the variation branches off
+    pub move_number: u32,
+
+    /// The variation as an SGF game tree, e.g. "(;W[dd];B[cc])"
+    pub sgf: String,
+}
+
+/// A scheduled check of a timed game's clock.
+///
+/// One check is scheduled for the moment the player to move would run out of time,
+/// so a player who stops moving still loses on time. Checks left over from earlier
+/// turns find the clock still running and do nothing.
+#[table(name = clock_check, scheduled(check_clock))]
+pub struct ClockCheck {
+    /// Unique identifier for the scheduled check
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+
+    /// When the check runs
+    pub scheduled_at: ScheduleAt,
+
+    /// The game whose clock is checked
+    #[index(btree)]
+    pub game_id: u64,
+}
+
+/// A move the built-in bot is due to make in a game against it.
+///
+/// One turn is scheduled shortly after each human move that leaves the bot to
+/// move, and replaced whenever the game changes before it runs.
+#[table(name = bot_turn, scheduled(play_bot_turn))]
+pub struct BotTurn {
+    /// Unique identifier for the scheduled turn
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+
+    /// When the bot moves
+    pub scheduled_at: ScheduleAt,
+
+    /// The game the bot moves in
+    #[index(btree)]
+    pub game_id: u64,
+}
+
+/// The SGF record of a game, written when a client asks for it with `export_sgf`.
+///
+/// Exporting again replaces the record, so an ongoing game can be fetched as often
+/// as needed.
+#[table(name = game_sgf, public)]
+pub struct GameSgf {
+    /// The game the record belongs to
+    #[primary_key]
+    pub game_id: u64,
+
+    /// The game in SGF (FF[4]) format
+    pub sgf: String,
+
+    /// When the record was written
+    pub exported_at: Timestamp,
+}
+
+impl GameComment {
+    /// Builds the table entry for a comment in a game record.
+    pub fn from_sgf(game_id: u64, comment: &SgfComment) -> Self {
+        GameComment {
+            id: 0, // Assigned by auto_inc
+            game_id,
+            move_number: comment.move_number as u32,
+            text: comment.text.clone(),
+        }
+    }
+
+    /// Returns the comment as part of a game record.
+    pub fn to_sgf(&self) -> SgfComment {
+        SgfComment {
+            move_number: self.move_number as usize,
+            text: self.text.clone(),
+        }
+    }
+}
+
+impl GameMarkup {
+    /// Builds the table entry for a mark in a game record.
+    pub fn from_sgf(game_id: u64, board_size: u8, mark: &SgfMarkup) -> Self {
+        GameMarkup {
+            id: 0, // Assigned by auto_inc
+            game_id,
+            move_number: mark.move_number as u32,
+            shape: mark.shape.clone(),
+            point: mark.point.to_gtp(board_size),
+            label: mark.label.clone(),
+        }
+    }
+
+    /// Returns the mark as part of a game record, or None if its point is not on
+    /// the board.
+    pub fn to_sgf(&self, board_size: u8) -> Option<SgfMarkup> {
+        Some(SgfMarkup {
+            move_number: self.move_number as usize,
+            shape: self.shape.clone(),
+            point: Point::from_gtp(&self.point, board_size).ok()?,
+            label: self.label.clone(),
+        })
+    }
+}
+
+impl GameVariation {
+    /// Builds the table entry for a variation in a game record.
+    pub fn from_sgf(game_id: u64, variation: &SgfVariation) -> Self {
+        GameVariation {
+            id: 0, // Assigned by auto_inc
+            game_id,
+            move_number: variation.move_number as u32,
+            sgf: variation.sgf.clone(),
+        }
+    }
+
+    /// Returns the variation as part of a game record.
+    pub fn to_sgf(&self) -> SgfVariation {
+        SgfVariation {
+            move_number: self.move_number as usize,
+            sgf: self.sgf.clone(),
+        }
+    }
 }
 
 impl Game {
+    /// Returns the difficulty level of the built-in bot, falling back to the default
+    /// level if the stored one is unknown.
+    pub fn bot_level(&self) -> BotLevel {
+        self.bot_level
+            .as_deref()
+            .map(|level| {
+                level.parse().unwrap_or_else(|e| {
+                    log::error!("Game {}: {}", self.id, e);
+                    BotLevel::default()
+                })
+            })
+            .unwrap_or_default()
+    }
+
     /// Converts the JSON-serialized board into a Board struct.
     /// 
     /// # Returns
@@ -76,13 +386,319 @@ impl Game {
         let spots = serde_json::from_str(&self.board)?;
         Ok(Board::new(spots, self.board_size))
     }
-}
 
-/// Defines different methods for scoring a Go game.
-#[derive(Debug, Clone, Copy)]
-pub enum ScoringMethod {
-    /// Area scoring: stones on board + enclosed territory
-    Area,
-    /// Territory scoring: enclosed territory only (plus captured stones)
-    Territory,
+    /// Builds the move record entry for a move in this game.
+    ///
+    /// Plays and passes advance the game's move count and take the next move number;
+    /// handicap stones, setup stones and cleared points are numbered 0 and leave the
+    /// count unchanged.
+    ///
+    /// # Arguments
+    /// * `color` - The player who moved
+    /// * `action` - "play", "pass", "handicap", "setup" or "clear"
+    /// * `point` - Where the stone was placed, or None for a pass
+    /// * `captured` - Number of opponent stones the move captured
+    /// * `played_at` - When the move was played
+    ///
+    /// # Returns
+    /// The entry to insert into the `game_move` table
+    pub fn record_move(
+        &mut self,
+        color: &Occupant,
+        action: &str,
+        point: Option<Point>,
+        captured: u32,
+        played_at: Timestamp,
+    ) -> GameMove {
+        let move_number = if matches!(action, "handicap" | "setup" | "clear") {
+            0
+        } else {
+            self.move_count += 1;
+            self.move_count
+        };
+        GameMove {
+            id: 0, // Assigned by auto_inc
+            game_id: self.id,
+            move_number,
+            color: if *color == Occupant::White { "W" } else { "B" }.to_string(),
+            action: action.to_string(),
+            point: point.map(|p| p.to_gtp(self.board_size)),
+            captured,
+            played_at,
+        }
+    }
+
+    /// Returns the color `player` plays in this game, or None if they are not playing.
+    ///
+    /// A player who holds both seats, as in an imported game, plays whichever color
+    /// is to move.
+    pub fn player_color(&self, player: Identity) -> Option<Occupant> {
+        let black = player == self.player_black;
+        let white = Some(player) == self.player_white;
+        match (black, white) {
+            (true, true) => Some(if self.turn == "W" { Occupant::White } else { Occupant::Black }),
+            (true, false) => Some(Occupant::Black),
+            (false, true) => Some(Occupant::White),
+            (false, false) => None,
+        }
+    }
+
+    /// Plays a stone for `color`, capturing any opponent stones it leaves without
+    /// liberties, and passes the turn to the other player.
+    ///
+    /// Legality is checked against the game's ruleset and position history, but not
+    /// whose turn it is; clocks are left to the caller.
+    ///
+    /// # Arguments
+    /// * `color` - The color of the stone
+    /// * `point` - Where the stone is played
+    /// * `played_at` - When the move was played
+    ///
+    /// # Returns
+    /// * `Ok(GameMove)` - The entry to insert into the `game_move` table
+    /// * `Err(String)` - Why the move is illegal
+    pub fn play_stone(
+        &mut self,
+        color: &Occupant,
+        point: Point,
+        played_at: Timestamp,
+    ) -> Result<GameMove, String> {
+        let board = serde_json::from_str(&self.board)
+            .map_err(|_| "Failed to parse game board".to_string())?;
+        let (new_board, new_board_str, effect) = apply_move_to_board(
+            board,
+            self.board_size,
+            color.clone(),
+            point,
+            &self.position_history,
+            self.rules(),
+            self.move_count as u64 + 1,
+        )?;
+
+        // Count captured stones as prisoners; stones lost to suicide go to the opponent
+        let captured = effect.captured.len() as u32;
+        let suicided = effect.suicided.len() as u32;
+        if *color == Occupant::Black {
+            self.captures_black += captured;
+            self.captures_white += suicided;
+        } else {
+            self.captures_white += captured;
+            self.captures_black += suicided;
+        }
+
+        let entry = self.record_move(color, "play", Some(point), captured, played_at);
+        self.board = new_board_str.unwrap();
+        let next = if *color == Occupant::Black { Occupant::White } else { Occupant::Black };
+        self.turn = if next == Occupant::White { "W" } else { "B" }.to_string();
+        self.position_history
+            .push(position_key(position_hash(&new_board, self.board_size), &next));
+        self.passes = 0; // Reset pass counter after a stone is placed
+        Ok(entry)
+    }
+
+    /// Returns the stones marked dead during the scoring phase.
+    pub fn dead_points(&self) -> Vec<Point> {
+        self.dead_stones
+            .iter()
+            .filter_map(|vertex| Point::from_gtp(vertex, self.board_size).ok())
+            .collect()
+    }
+
+    /// Replaces the stones marked dead, which withdraws both players' acceptance.
+    pub fn set_dead_points(&mut self, points: &[Point]) {
+        self.dead_stones = points.iter().map(|p| p.to_gtp(self.board_size)).collect();
+        self.accepted_black = false;
+        self.accepted_white = false;
+    }
+
+    /// Ends the game with the given result.
+    pub fn finish(&mut self, result: &GameResult) {
+        self.game_over = true;
+        self.scoring_phase = false;
+        self.winner = result.winner.as_ref().map(|winner| {
+            if *winner == Occupant::White { "W" } else { "B" }.to_string()
+        });
+        self.result_reason = Some(result.reason.as_str().to_string());
+        self.result_margin = result.margin;
+        self.result = Some(result.to_notation());
+    }
+
+    /// Returns the time control of a timed game, or None for an untimed game.
+    pub fn time_control(&self) -> Option<TimeControl> {
+        let json = self.time_control.as_ref()?;
+        serde_json::from_str(json)
+            .map_err(|e| log::error!("Game {}: invalid time control: {}", self.id, e))
+            .ok()
+    }
+
+    /// Returns the clock of the given player in a timed game.
+    pub fn clock(&self, color: &Occupant) -> Option<Clock> {
+        let json = match color {
+            Occupant::White => self.clock_white.as_ref(),
+            _ => self.clock_black.as_ref(),
+        }?;
+        serde_json::from_str(json)
+            .map_err(|e| log::error!("Game {}: invalid clock: {}", self.id, e))
+            .ok()
+    }
+
+    /// Stores the clock of the given player.
+    pub fn set_clock(&mut self, color: &Occupant, clock: &Clock) {
+        let json = serde_json::to_string(clock).ok();
+        match color {
+            Occupant::White => self.clock_white = json,
+            _ => self.clock_black = json,
+        }
+    }
+
+    /// Returns how long the player to move has been thinking, in milliseconds.
+    pub fn turn_elapsed_ms(&self, now: Timestamp) -> u64 {
+        self.turn_started_at
+            .and_then(|started| now.duration_since(started))
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or(0)
+    }
+
+    /// Charges the time the player to move spent on their turn to their clock.
+    ///
+    /// Untimed games, and games whose clock has not started yet, are not charged.
+    ///
+    /// # Arguments
+    /// * `color` - The player who moved
+    /// * `now` - When the move was made
+    ///
+    /// # Returns
+    /// * `Ok(())` - The move was made in time
+    /// * `Err(message)` - If the player ran out of time
+    pub fn charge_clock(&mut self, color: &Occupant, now: Timestamp) -> Result<(), String> {
+        let (Some(control), Some(mut clock)) = (self.time_control(), self.clock(color)) else {
+            return Ok(());
+        };
+        if self.turn_started_at.is_none() {
+            return Ok(());
+        }
+        clock.charge_move(&control, self.turn_elapsed_ms(now))?;
+        self.set_clock(color, &clock);
+        Ok(())
+    }
+
+    /// Returns when the player to move runs out of time, if the game is timed and
+    /// their clock is running.
+    pub fn flag_time(&self) -> Option<Timestamp> {
+        let to_move = if self.turn == "W" { Occupant::White } else { Occupant::Black };
+        let control = self.time_control()?;
+        let remaining = self.clock(&to_move)?.time_remaining(&control);
+        self.turn_started_at?
+            .checked_add_duration(std::time::Duration::from_millis(remaining))
+    }
+
+    /// Builds the SGF game record of this game from its move record.
+    ///
+    /// Comments, markup and variations live in tables of their own and are left
+    /// for the caller to add.
+    ///
+    /// # Arguments
+    /// * `moves` - The game's move record, in the order the moves were played
+    /// * `player_black` - Name of the Black player, if known
+    /// * `player_white` - Name of the White player, if known
+    ///
+    /// # Returns
+    /// The record to pass to `go_engine::sgf::write_sgf`
+    pub fn sgf_record(
+        &self,
+        moves: &[GameMove],
+        player_black: Option<String>,
+        player_white: Option<String>,
+    ) -> SgfGame {
+        let mut handicap_stones = Vec::new();
+        let mut setup_white = Vec::new();
+        let mut setup: Vec<SgfSetup> = Vec::new();
+        let mut sgf_moves: Vec<SgfMove> = Vec::new();
+        for mv in moves {
+            let color = if mv.color == "W" { Occupant::White } else { Occupant::Black };
+            let point = mv
+                .point
+                .as_ref()
+                .and_then(|vertex| Point::from_gtp(vertex, self.board_size).ok());
+            let action = mv.action.as_str();
+            let starting_position = sgf_moves.is_empty() && setup.is_empty();
+            match action {
+                "handicap" | "setup" if starting_position => match color {
+                    Occupant::White => setup_white.extend(point),
+                    _ => handicap_stones.extend(point),
+                },
+                "handicap" | "setup" | "clear" => {
+                    // Stones are cleared before they are added within a step, so a
+                    // clear after an added stone starts the next one
+                    let before_move = sgf_moves.len();
+                    let same_step = setup.last().is_some_and(|step| {
+                        step.before_move == before_move
+                            && (action != "clear"
+                                || (step.black.is_empty() && step.white.is_empty()))
+                    });
+                    if !same_step {
+                        setup.push(SgfSetup {
+                            before_move,
+                            ..SgfSetup::default()
+                        });
+                    }
+                    let step = setup.last_mut().unwrap();
+                    if action != "clear" {
+                        // An added stone replaces the one cleared from its point, and
+                        // SGF allows a point in only one of AB, AW and AE
+                        step.empty.retain(|&cleared| Some(cleared) != point);
+                    }
+                    let points = match (action, color) {
+                        ("clear", _) => &mut step.empty,
+                        (_, Occupant::White) => &mut step.white,
+                        _ => &mut step.black,
+                    };
+                    points.extend(point);
+                }
+                "play" | "pass" => sgf_moves.push(SgfMove {
+                    color,
+                    point,
+                    played_at: mv.played_at.to_rfc3339().ok(),
+                }),
+                _ => {} // A resignation is recorded in the result
+            }
+        }
+
+        // Black moves first unless the record says otherwise
+        let to_move = if self.turn == "W" { Occupant::White } else { Occupant::Black };
+        let first_player = sgf_moves.first().map_or(to_move, |mv| mv.color.clone());
+
+        SgfGame {
+            board_size: self.board_size,
+            komi: self.komi,
+            ruleset: self.rules(),
+            handicap: self.handicap,
+            handicap_stones,
+            setup_white,
+            first_player: (first_player == Occupant::White).then_some(Occupant::White),
+            player_black,
+            player_white,
+            date: moves
+                .first()
+                .and_then(|mv| mv.played_at.to_rfc3339().ok())
+                .map(|timestamp| timestamp[..10].to_string()),
+            time_control: self.time_control(),
+            result: self.result.as_ref().and_then(|result| result.parse().ok()),
+            moves: sgf_moves,
+            setup,
+            comments: Vec::new(),
+            markup: Vec::new(),
+            variations: Vec::new(),
+        }
+    }
+
+    /// Returns the ruleset this game is played under.
+    ///
+    /// Falls back to the default ruleset if the stored identifier is not recognised.
+    pub fn rules(&self) -> Ruleset {
+        self.ruleset.parse().unwrap_or_else(|e| {
+            log::error!("Game {}: {}", self.id, e);
+            Ruleset::default()
+        })
+    }
 }
@@ -1,18 +1,11 @@
 /*!
- * Core data models for the Go game.
+ * Database tables for the Go game.
  *
- * This module contains all the fundamental types and structures used
- * to represent game state, boards, stones, and players.
+ * This module contains the SpacetimeDB tables that store users, chat
+ * messages, and games. The board, stone, and ruleset types they refer to
+ * live in the `go-engine` crate.
  */
 
-pub mod board;
 pub mod game;
-pub mod group;
-pub mod region;
-pub mod spot;
 
-pub use board::*;
 pub use game::*;
-pub use group::*;
-pub use region::*;
-pub use spot::*;
@@ -0,0 +1,135 @@
+use crate::models::game::{bot_turn, game, user};
+use crate::models::{BotTurn, Game, User};
+use crate::reducers::game_reducers::{play_pass, play_stone, resign_game};
+use go_engine::bot::{choose_mcts_move, BotInput, BotMove};
+use go_engine::models::Occupant;
+use spacetimedb::rand::Rng;
+use spacetimedb::{reducer, Identity, ReducerContext, Table};
+use std::time::Duration;
+
+/// Display name of the built-in bot.
+const BOT_NAME: &str = "GoTime Bot";
+
+/// How long the bot waits before answering a move, so its stone doesn't appear in
+/// the same instant as its opponent's.
+const BOT_MOVE_DELAY: Duration = Duration::from_millis(500);
+
+/// Makes sure the built-in bot has a user record, so its name shows up like any
+/// other player's.
+///
+/// The bot plays with the module's own identity, which no client can send requests as.
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+///
+/// # Returns
+/// The bot's identity
+pub fn register_bot(ctx: &ReducerContext) -> Identity {
+    let identity = ctx.identity();
+    if ctx.db.user().identity().find(identity).is_none() {
+        ctx.db.user().insert(User {
+            identity,
+            name: Some(BOT_NAME.to_string()),
+            online: true,
+        });
+    }
+    identity
+}
+
+/// Returns the color the built-in bot plays in a game, or None if it is not playing.
+pub fn bot_color(ctx: &ReducerContext, game: &Game) -> Option<Occupant> {
+    if game.player_black == ctx.identity() {
+        Some(Occupant::Black)
+    } else if game.player_white == Some(ctx.identity()) {
+        Some(Occupant::White)
+    } else {
+        None
+    }
+}
+
+/// Schedules the bot's next move if it is the bot's turn to play, replacing any move
+/// scheduled earlier in the game.
+///
+/// Nothing is scheduled while the game is over, being scored, or waiting for
+/// handicap stones.
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+/// * `game` - The game as it stands after the latest change
+pub fn schedule_bot_move(ctx: &ReducerContext, game: &Game) {
+    ctx.db.bot_turn().game_id().delete(game.id);
+    if game.game_over || game.scoring_phase || game.handicap_pending > 0 {
+        return;
+    }
+    let to_move = if game.turn == "W" { Occupant::White } else { Occupant::Black };
+    if bot_color(ctx, game) != Some(to_move) {
+        return;
+    }
+    if let Some(move_time) = ctx.timestamp.checked_add_duration(BOT_MOVE_DELAY) {
+        ctx.db.bot_turn().insert(BotTurn {
+            scheduled_id: 0, // Assigned by auto_inc
+            scheduled_at: move_time.into(),
+            game_id: game.id,
+        });
+    }
+}
+
+/// Makes the built-in bot's move in a game against it.
+///
+/// The move is chosen by `go_engine::bot::choose_mcts_move` with the game's
+/// difficulty level, seeded from the reducer's random number generator, and played
+/// through the same path as a player's move. The bot passes or resigns when the
+/// engine says so.
+///
+/// This reducer is only run by the scheduler, at the time stored in a [`BotTurn`].
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+/// * `turn` - The scheduled turn that fired
+///
+/// # Returns
+/// * `Ok(())` - The bot moved, or there was nothing to do
+/// * `Err(String)` - If the reducer was called by a client
+#[reducer]
+pub fn play_bot_turn(ctx: &ReducerContext, turn: BotTurn) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Bot moves may only be run by the scheduler".to_string());
+    }
+
+    let Some(game) = ctx.db.game().id().find(turn.game_id) else {
+        return Ok(());
+    };
+    if game.game_over || game.scoring_phase || game.handicap_pending > 0 {
+        return Ok(());
+    }
+    let color = if game.turn == "W" { Occupant::White } else { Occupant::Black };
+    if bot_color(ctx, &game) != Some(color.clone()) {
+        return Ok(());
+    }
+
+    let board = game
+        .as_board()
+        .map_err(|_| "Failed to parse game board".to_string())?;
+    let rules = game.rules();
+    let input = BotInput {
+        board: &board,
+        to_move: &color,
+        ruleset: rules,
+        position_history: &game.position_history,
+        komi: game.komi + rules.handicap_compensation(game.handicap),
+        captures: (game.captures_black, game.captures_white),
+        move_count: game.move_count,
+        opponent_passed: game.passes > 0,
+    };
+    let config = game.bot_level().mcts_config(ctx.rng().gen());
+    let bot_move = choose_mcts_move(&input, &config);
+
+    match bot_move {
+        BotMove::Play(point) => play_stone(ctx, game, color, point),
+        BotMove::Pass => play_pass(ctx, game, color),
+        BotMove::Resign => {
+            resign_game(ctx, game, color);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::models::game::{clock_check, game};
+use crate::models::{ClockCheck, Game};
+use go_engine::models::{GameResult, Occupant, ResultReason};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// Schedules a check of a timed game's clock for the moment the player to move
+/// would run out of time, replacing any check scheduled for an earlier turn.
+///
+/// Untimed and finished games, and games whose clock has not started, are not checked.
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+/// * `game` - The game whose clock is running
+pub fn schedule_clock_check(ctx: &ReducerContext, game: &Game) {
+    ctx.db.clock_check().game_id().delete(game.id);
+    if game.game_over {
+        return;
+    }
+    if let Some(flag_time) = game.flag_time() {
+        ctx.db.clock_check().insert(ClockCheck {
+            scheduled_id: 0, // Assigned by auto_inc
+            scheduled_at: flag_time.into(),
+            game_id: game.id,
+        });
+    }
+}
+
+/// Ends a game as lost on time by the given player.
+///
+/// # Arguments
+/// * `game` - The game to end
+/// * `loser` - The player whose flag fell
+pub fn flag_fall(game: &mut Game, loser: &Occupant) {
+    let winner = if *loser == Occupant::White {
+        Occupant::Black
+    } else {
+        Occupant::White
+    };
+    game.finish(&GameResult::win_by(winner, ResultReason::Timeout));
+    log::info!("Game {} ended: {}", game.id, game.result.as_deref().unwrap_or("?"));
+}
+
+/// Checks whether the player to move has run out of time, and if so ends the game.
+///
+/// This reducer is only run by the scheduler, at the time stored in a [`ClockCheck`].
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+/// * `check` - The scheduled check that fired
+///
+/// # Returns
+/// * `Ok(())` - The clock was checked
+/// * `Err(String)` - If the reducer was called by a client
+#[reducer]
+pub fn check_clock(ctx: &ReducerContext, check: ClockCheck) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Clock checks may only be run by the scheduler".to_string());
+    }
+
+    let Some(mut game) = ctx.db.game().id().find(check.game_id) else {
+        return Ok(());
+    };
+    if game.game_over {
+        return Ok(());
+    }
+
+    let to_move = if game.turn == "W" { Occupant::White } else { Occupant::Black };
+    let (Some(control), Some(clock)) = (game.time_control(), game.clock(&to_move)) else {
+        return Ok(());
+    };
+    if game.turn_started_at.is_none() {
+        return Ok(());
+    }
+
+    // A player who moved since this check was scheduled is still on the clock
+    if clock.is_flagged(&control, game.turn_elapsed_ms(ctx.timestamp)) {
+        flag_fall(&mut game, &to_move);
+        ctx.db.game().id().update(game);
+    }
+    Ok(())
+}
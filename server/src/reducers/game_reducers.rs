@@ -1,8 +1,16 @@
-use crate::models::game::game;
-use crate::models::{Game, Occupant, SpotState};
+use crate::models::game::{game, game_move};
+use crate::models::Game;
+use crate::reducers::bot_reducers::{register_bot, schedule_bot_move};
+use crate::reducers::clock_reducers::{flag_fall, schedule_clock_check};
 use crate::scoring::analyze_game;
 use crate::seed::seed_sample_games;
-use crate::utils::{apply_move_to_board, coord_to_index};
+use go_engine::bot::BotLevel;
+use go_engine::models::{
+    position_key, validate_board_size, validate_komi, GameResult, Occupant, Point,
+    ResultReason, Ruleset, ScoringMethod, SpotState, TimeControl,
+};
+use go_engine::scoring::find_dead_stones;
+use go_engine::utils::{fixed_handicap_points, position_hash};
 use serde_json;
 use spacetimedb::{reducer, ReducerContext, Table};
 use std::convert::TryInto;
@@ -10,23 +18,57 @@ use std::convert::TryInto;
 /// Default board size when not specified by the user
 const DEFAULT_BOARD_SIZE: u8 = 9;
 
-/// Creates a new Go game with optional custom board size and handicap.
+/// Creates a new Go game with optional custom board size, handicap, ruleset and
+/// time control.
 ///
 /// # Arguments
 /// * `ctx` - The reducer context containing sender identity and timestamp
 /// * `board_size` - Optional board size (defaults to 9×9 if not specified)
-/// * `handicap` - Optional handicap stones to place for the black player
+/// * `handicap` - Optional number of handicap stones for the black player, from 2 to 9
+///   (fewer on even boards and 7×7 under fixed placement)
+/// * `free_handicap` - Whether Black places the handicap stones freely with
+///   [`place_handicap_stone`] instead of on the standard points (defaults to false)
+/// * `ruleset` - Optional ruleset identifier such as "japanese" (defaults to Chinese rules)
+/// * `komi` - Optional komi for White; may be a whole number or negative (defaults to the
+///   ruleset's komi for the board size and handicap)
+/// * `time_control` - Optional JSON time control (see [`TimeControl`]); untimed if omitted
+/// * `bot_level` - Optional difficulty level ("beginner", "easy", "medium" or "hard");
+///   if given, White is the built-in bot instead of a second player
 ///
 /// # Returns
 /// * `Ok(())` - Game was created successfully
 /// * `Err(String)` - Error message if the creation failed
 #[reducer]
+#[allow(clippy::too_many_arguments)] // Each option is a reducer argument for clients
 pub fn create_game(
     ctx: &ReducerContext,
     board_size: Option<u8>,
     handicap: Option<u8>,
+    free_handicap: Option<bool>,
+    ruleset: Option<String>,
+    komi: Option<f32>,
+    time_control: Option<String>,
+    bot_level: Option<String>,
 ) -> Result<(), String> {
     let size = board_size.unwrap_or(DEFAULT_BOARD_SIZE);
+    validate_board_size(size)?;
+    let ruleset = match ruleset {
+        Some(name) => name.parse::<Ruleset>()?,
+        None => Ruleset::default(),
+    };
+    let bot_level = match bot_level {
+        Some(name) => Some(name.parse::<BotLevel>()?),
+        None => None,
+    };
+    let time_control = match time_control {
+        Some(json) => {
+            let control: TimeControl = serde_json::from_str(&json)
+                .map_err(|e| format!("Invalid time control: {}", e))?;
+            control.validate()?;
+            Some(control)
+        }
+        None => None,
+    };
 
     // Use the timestamp as a unique game ID
     let game_id: u64 = ctx
@@ -43,57 +85,112 @@ pub fn create_game(
             marker: None,
             scoring_owner: None,
             scoring_explanation: None,
+            group_status: None,
             playable: true,
         })
         .collect();
 
     // Apply handicap if requested (pre-place black stones)
-    let handicap = handicap.unwrap_or(0).min(9); // Cap at 9 handicap stones
-
-    if handicap > 0 {
-        // Calculate handicap stone positions based on standard Go patterns
-        let handicap_positions = match size {
-            9 => vec![(2, 2), (6, 6), (2, 6), (6, 2), (4, 4)],
-            13 => vec![(3, 3), (9, 9), (3, 9), (9, 3), (6, 6)],
-            19 => vec![(3, 3), (15, 15), (15, 3), (3, 15), (9, 9)],
-            _ => vec![], // No handicap for non-standard board sizes
-        };
-
-        // Place the handicap stones on the board
-        for i in 0..(handicap as usize).min(handicap_positions.len()) {
-            let (x, y) = handicap_positions[i];
-            let idx = coord_to_index(x, y, size as usize);
+    let handicap = handicap.unwrap_or(0);
+    let mut handicap_stones = Vec::new();
+    let free_handicap = free_handicap.unwrap_or(false);
+
+    if free_handicap {
+        // Black places the stones before White's first move
+        if !(2..=9).contains(&handicap) {
+            return Err("Free handicap placement needs between 2 and 9 stones".to_string());
+        }
+    } else if handicap > 0 {
+        // Place the stones on the standard points for the board size
+        for point in fixed_handicap_points(size, handicap)? {
+            let idx = point.index(size);
             board[idx].occupant = Occupant::Black;
-            board[idx].move_number = Some(ctx.timestamp.to_micros_since_unix_epoch() as u64);
+            board[idx].move_number = Some(0); // Handicap stones precede the first move
+            handicap_stones.push(point);
         }
     }
 
-    // If handicap is used, White goes first; otherwise Black goes first
-    let turn = if handicap > 0 {
-        "W".to_string()
+    // Use the ruleset's komi unless the players agreed on their own
+    let komi = match komi {
+        Some(komi) => {
+            validate_komi(komi, size)?;
+            komi
+        }
+        None => ruleset.default_komi(handicap, size),
+    };
+
+    // If handicap is used, White goes first; otherwise Black goes first, starting with
+    // the handicap stones under free placement
+    let (turn, to_move) = if handicap > 0 && !free_handicap {
+        ("W".to_string(), Occupant::White)
     } else {
-        "B".to_string()
+        ("B".to_string(), Occupant::Black)
     };
 
     // Serialize the board to JSON for storage
     let board_json = serde_json::to_string(&board).unwrap();
+    let position_history = vec![position_key(position_hash(&board, size), &to_move)];
 
-    // Insert the new game into the database
-    ctx.db.game().insert(Game {
+    let mut game = Game {
         id: game_id,
         player_black: ctx.sender,
-        player_white: None, // Will be filled when a second player joins
+        player_white: None, // Will be filled when a second player joins or the bot is chosen
         board: board_json,
         turn,
         passes: 0,
         board_size: size,
-        previous_board: None,
+        position_history,
+        ruleset: ruleset.as_str().to_string(),
+        handicap,
+        handicap_pending: if free_handicap { handicap } else { 0 },
+        komi,
+        captures_black: 0,
+        captures_white: 0,
+        move_count: 0,
         game_over: false,
+        scoring_phase: false,
+        dead_stones: Vec::new(),
+        accepted_black: false,
+        accepted_white: false,
         final_score_black: None,
         final_score_white: None,
-    });
+        winner: None,
+        result_reason: None,
+        result_margin: None,
+        result: None,
+        time_control: None,
+        clock_black: None,
+        clock_white: None,
+        turn_started_at: None, // The clock starts when White joins
+        bot_level: None,
+    };
 
-    log::info!("Created game with id: {}", game_id);
+    // Both players start with the full time allowance
+    if let Some(control) = time_control {
+        game.time_control = serde_json::to_string(&control).ok();
+        game.set_clock(&Occupant::Black, &control.initial_clock());
+        game.set_clock(&Occupant::White, &control.initial_clock());
+    }
+
+    // The bot takes White straight away, so the clock starts once play can begin
+    if let Some(level) = bot_level {
+        game.player_white = Some(register_bot(ctx));
+        game.bot_level = Some(level.as_str().to_string());
+        if game.handicap_pending == 0 {
+            game.turn_started_at = Some(ctx.timestamp);
+            schedule_clock_check(ctx, &game);
+        }
+        schedule_bot_move(ctx, &game);
+    }
+
+    // Record the handicap stones, then insert the new game into the database
+    for point in handicap_stones {
+        let entry = game.record_move(&Occupant::Black, "handicap", Some(point), 0, ctx.timestamp);
+        ctx.db.game_move().insert(entry);
+    }
+    ctx.db.game().insert(game);
+
+    log::info!("Created game with id: {} ({} rules)", game_id, ruleset);
     Ok(())
 }
 
@@ -110,9 +207,9 @@ pub fn create_game(
 pub fn join_game(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
     if let Some(mut game) = ctx.db.game().id().find(game_id) {
         // Check if the game already has two players
-        if game.player_white.is_some() {
+        if let Some(player_white) = game.player_white {
             // Allow the existing players to rejoin
-            if ctx.sender != game.player_black && ctx.sender != game.player_white.unwrap() {
+            if ctx.sender != game.player_black && ctx.sender != player_white {
                 return Err("Game already has two players".to_string());
             }
             return Ok(());
@@ -123,8 +220,13 @@ pub fn join_game(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
             return Err("You are already in this game as Black".to_string());
         }
 
-        // Join as White
+        // Join as White, which starts the first player's clock unless Black is still
+        // placing handicap stones
         game.player_white = Some(ctx.sender);
+        if game.handicap_pending == 0 {
+            game.turn_started_at = Some(ctx.timestamp);
+            schedule_clock_check(ctx, &game);
+        }
         ctx.db.game().id().update(game);
         log::info!("Player {} joined game {}", ctx.sender, game_id);
         Ok(())
@@ -136,7 +238,9 @@ pub fn join_game(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
 /// Player passes their turn.
 ///
 /// In Go, a player can choose to "pass" instead of placing a stone.
-/// If both players pass consecutively, the game ends.
+/// If both players pass consecutively, play stops and the game enters the scoring
+/// phase, where the players agree on which stones are dead before it is counted.
+/// Games under Tromp-Taylor rules skip the scoring phase and are counted straight away.
 ///
 /// # Arguments
 /// * `ctx` - The reducer context containing sender identity
@@ -147,7 +251,7 @@ pub fn join_game(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
 /// * `Err(String)` - Error message if the pass failed
 #[reducer]
 pub fn pass_move(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
-    let mut game = match ctx.db.game().id().find(game_id) {
+    let game = match ctx.db.game().id().find(game_id) {
         Some(g) => g,
         None => return Err(format!("Game with id {} not found", game_id)),
     };
@@ -156,40 +260,220 @@ pub fn pass_move(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
     if game.game_over {
         return Err("Game is already over".to_string());
     }
+    if game.scoring_phase {
+        return Err("The game is being scored; resume play to make a move".to_string());
+    }
+    if game.handicap_pending > 0 {
+        return Err(format!(
+            "Black must first place {} more handicap stone(s)",
+            game.handicap_pending
+        ));
+    }
 
     // Verify it's the sender's turn
-    let is_sender_turn = match game.turn.as_str() {
-        "B" => ctx.sender == game.player_black,
-        "W" => game
-            .player_white
-            .map(|pw| ctx.sender == pw)
-            .unwrap_or(false),
-        _ => false,
+    let Some(color) = game.player_color(ctx.sender) else {
+        return Err("You are not a player in this game".to_string());
     };
-
-    if !is_sender_turn {
+    let to_move = if game.turn == "B" { Occupant::Black } else { Occupant::White };
+    if color != to_move {
         return Err("It's not your turn".to_string());
     }
 
-    // Increment pass counter
+    play_pass(ctx, game, color)
+}
+
+/// Records a pass by the player to move, whose turn has already been checked.
+///
+/// This is the shared path for passes by players and by the built-in bot.
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+/// * `game` - The game, with `color` to move
+/// * `color` - The player passing
+///
+/// # Returns
+/// * `Ok(())` - The pass was recorded, or the player lost on time
+/// * `Err(String)` - Error message if the game could not be updated
+pub fn play_pass(ctx: &ReducerContext, mut game: Game, color: Occupant) -> Result<(), String> {
+    // A player who passes after their flag has fallen loses on time
+    if game.charge_clock(&color, ctx.timestamp).is_err() {
+        flag_fall(&mut game, &color);
+        ctx.db.game().id().update(game);
+        return Ok(());
+    }
+
+    // Record the pass and increment the pass counter
+    let entry = game.record_move(&color, "pass", None, 0, ctx.timestamp);
+    ctx.db.game_move().insert(entry);
     game.passes += 1;
 
-    // If both players have passed, end the game
-    if game.passes >= 2 {
+    // Switch turns and record the unchanged board with the other player to move
+    game.turn = if game.turn == "B" { "W" } else { "B" }.to_string();
+    let next = if game.turn == "B" { Occupant::Black } else { Occupant::White };
+    let board = game
+        .as_board()
+        .map_err(|_| "Failed to parse game board".to_string())?;
+    game.position_history.push(position_key(board.position_hash(), &next));
+
+    if game.passes >= 2 && game.rules().scoring_method() == ScoringMethod::TrompTaylor {
+        // Tromp-Taylor games are counted exactly as they stand, with no dead stones
+        // to agree on
         game.game_over = true;
+        game.turn_started_at = None;
+    } else if game.passes >= 2 {
+        // Both players have passed: stop the clocks while they mark dead stones,
+        // starting from the stones Benson's algorithm proves dead
+        game.scoring_phase = true;
+        game.set_dead_points(&find_dead_stones(&board));
+        game.turn_started_at = None;
+        log::info!("Game {} entered the scoring phase", game.id);
     } else {
-        // Switch turns
-        game.turn = if game.turn == "B" { "W" } else { "B" }.to_string();
+        // Start the other player's clock
+        game.turn_started_at = Some(ctx.timestamp);
     }
+    schedule_clock_check(ctx, &game);
 
     // Analyze the game and update scores
     game = analyze_game(game);
 
+    // A game ended by two passes is decided by the count
+    if game.game_over {
+        let scores = (game.final_score_black, game.final_score_white);
+        if let (Some(black_score), Some(white_score)) = scores {
+            game.finish(&GameResult::from_score(black_score, white_score));
+        }
+        log::info!("Game {} ended: {}", game.id, game.result.as_deref().unwrap_or("?"));
+    }
+    schedule_bot_move(ctx, &game);
+
     // Update the game state
     ctx.db.game().id().update(game);
     Ok(())
 }
 
+/// Player resigns the game, which their opponent then wins.
+///
+/// A player may resign at any point once both players have joined, whether or not
+/// it is their turn.
+///
+/// # Arguments
+/// * `ctx` - The reducer context containing sender identity
+/// * `game_id` - The ID of the game
+///
+/// # Returns
+/// * `Ok(())` - Resignation was recorded
+/// * `Err(String)` - Error message if the resignation failed
+#[reducer]
+pub fn resign(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
+    let game = match ctx.db.game().id().find(game_id) {
+        Some(g) => g,
+        None => return Err(format!("Game with id {} not found", game_id)),
+    };
+
+    // Verify the game isn't already over
+    if game.game_over {
+        return Err("Game is already over".to_string());
+    }
+
+    // Verify the game has two players
+    if game.player_white.is_none() {
+        return Err("Waiting for second player to join".to_string());
+    }
+
+    // Determine which player is resigning
+    let Some(color) = game.player_color(ctx.sender) else {
+        return Err("You are not a player in this game".to_string());
+    };
+
+    resign_game(ctx, game, color);
+    Ok(())
+}
+
+/// Records a resignation and ends the game as won by the other player.
+///
+/// This is the shared path for resignations by players and by the built-in bot.
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+/// * `game` - The game, which must not be over
+/// * `color` - The player resigning
+pub fn resign_game(ctx: &ReducerContext, mut game: Game, color: Occupant) {
+    let winner = if color == Occupant::Black {
+        Occupant::White
+    } else {
+        Occupant::Black
+    };
+    let entry = game.record_move(&color, "resign", None, 0, ctx.timestamp);
+    ctx.db.game_move().insert(entry);
+    game.finish(&GameResult::win_by(winner, ResultReason::Resignation));
+    log::info!("Game {} ended: {}", game.id, game.result.as_deref().unwrap_or("?"));
+
+    schedule_bot_move(ctx, &game);
+    ctx.db.game().id().update(game);
+}
+
+/// Places one of Black's handicap stones in a game with free handicap placement.
+///
+/// Black places all handicap stones, one call per stone, before White's first move.
+/// Once the last stone is placed, it is White's turn and White's clock starts.
+///
+/// # Arguments
+/// * `ctx` - The reducer context containing sender identity
+/// * `game_id` - The ID of the game
+/// * `x` - The x-coordinate (column) for the stone
+/// * `y` - The y-coordinate (row) for the stone
+///
+/// # Returns
+/// * `Ok(())` - Handicap stone was placed successfully
+/// * `Err(String)` - Error message if the placement failed
+#[reducer]
+pub fn place_handicap_stone(ctx: &ReducerContext, game_id: u64, x: u8, y: u8) -> Result<(), String> {
+    let mut game = match ctx.db.game().id().find(game_id) {
+        Some(g) => g,
+        None => return Err(format!("Game with id {} not found", game_id)),
+    };
+
+    // Verify the game isn't already over
+    if game.game_over {
+        return Err("Game is already over".to_string());
+    }
+    if ctx.sender != game.player_black {
+        return Err("Only Black places handicap stones".to_string());
+    }
+    if game.handicap_pending == 0 {
+        return Err("There are no handicap stones left to place".to_string());
+    }
+
+    let board: Vec<SpotState> = serde_json::from_str(&game.board)
+        .map_err(|_| "Failed to parse game board".to_string())?;
+    let point = Point::on_board(y as usize, x as usize, game.board_size)?;
+    let board = go_engine::utils::place_handicap_stone(board, game.board_size, point)?;
+
+    let entry = game.record_move(&Occupant::Black, "handicap", Some(point), 0, ctx.timestamp);
+    ctx.db.game_move().insert(entry);
+    game.handicap_pending -= 1;
+
+    if game.handicap_pending == 0 {
+        // All handicap stones are placed: play starts from here with White to move
+        game.turn = "W".to_string();
+        game.position_history = vec![position_key(
+            position_hash(&board, game.board_size),
+            &Occupant::White,
+        )];
+        if game.player_white.is_some() {
+            game.turn_started_at = Some(ctx.timestamp);
+            schedule_clock_check(ctx, &game);
+        }
+    }
+    game.board = serde_json::to_string(&board)
+        .map_err(|e| format!("Failed to serialize board: {}", e))?;
+    schedule_bot_move(ctx, &game);
+
+    game = analyze_game(game);
+    ctx.db.game().id().update(game);
+    Ok(())
+}
+
 /// Places a stone on the board at the specified coordinates.
 ///
 /// This is the main gameplay action that handles:
@@ -210,7 +494,7 @@ pub fn pass_move(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
 /// * `Err(String)` - Error message if the placement failed
 #[reducer]
 pub fn place_stone(ctx: &ReducerContext, game_id: u64, x: u8, y: u8) -> Result<(), String> {
-    let mut game = match ctx.db.game().id().find(game_id) {
+    let game = match ctx.db.game().id().find(game_id) {
         Some(g) => g,
         None => return Err(format!("Game with id {} not found", game_id)),
     };
@@ -220,17 +504,23 @@ pub fn place_stone(ctx: &ReducerContext, game_id: u64, x: u8, y: u8) -> Result<(
         return Err("Game is already over".to_string());
     }
 
+    if game.scoring_phase {
+        return Err("The game is being scored; resume play to make a move".to_string());
+    }
+    if game.handicap_pending > 0 {
+        return Err(format!(
+            "Black must first place {} more handicap stone(s)",
+            game.handicap_pending
+        ));
+    }
+
     // Verify the game has two players
     if game.player_white.is_none() {
         return Err("Waiting for second player to join".to_string());
     }
 
     // Determine which stone color the sender is playing
-    let stone_color = if ctx.sender == game.player_black {
-        Occupant::Black
-    } else if Some(ctx.sender) == game.player_white {
-        Occupant::White
-    } else {
+    let Some(stone_color) = game.player_color(ctx.sender) else {
         return Err("You are not a player in this game".to_string());
     };
 
@@ -245,29 +535,45 @@ pub fn place_stone(ctx: &ReducerContext, game_id: u64, x: u8, y: u8) -> Result<(
         return Err("It's not your turn".to_string());
     }
 
-    // Convert board from JSON string to a vector of SpotState
-    let board_result: Result<Vec<SpotState>, _> = serde_json::from_str(&game.board);
-    let board = match board_result {
-        Ok(b) => b,
-        Err(_) => return Err("Failed to parse game board".to_string()),
-    };
+    let point = Point::on_board(y as usize, x as usize, game.board_size)?;
+    play_stone(ctx, game, stone_color, point)
+}
 
-    // Apply the move to the board
-    let (_new_board, new_board_str) = apply_move_to_board(
-        board,
-        game.board_size as usize,
-        stone_color,
-        x as usize,
-        y as usize,
-        game.previous_board.clone(),
-        ctx.timestamp.to_micros_since_unix_epoch() as u64,
-    )?;
+/// Plays a stone for the player to move, whose turn has already been checked.
+///
+/// This is the shared path for moves by players and by the built-in bot.
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+/// * `game` - The game, with `stone_color` to move
+/// * `stone_color` - The color of the stone
+/// * `point` - Where the stone is played
+///
+/// # Returns
+/// * `Ok(())` - Stone was placed successfully, or the player lost on time
+/// * `Err(String)` - Error message if the move is illegal
+pub fn play_stone(
+    ctx: &ReducerContext,
+    mut game: Game,
+    stone_color: Occupant,
+    point: Point,
+) -> Result<(), String> {
+    // A player who moves after their flag has fallen loses on time
+    if game.charge_clock(&stone_color, ctx.timestamp).is_err() {
+        flag_fall(&mut game, &stone_color);
+        ctx.db.game().id().update(game);
+        return Ok(());
+    }
 
-    // Update the game state
-    game.board = new_board_str.unwrap();
-    game.previous_board = Some(game.board.clone());
-    game.turn = if game.turn == "B" { "W" } else { "B" }.to_string();
-    game.passes = 0; // Reset pass counter after a stone is placed
+    // Apply the move to the board and record it
+    let entry = game.play_stone(&stone_color, point, ctx.timestamp)?;
+    ctx.db.game_move().insert(entry);
+
+    // Start the other player's clock
+    game.turn_started_at = Some(ctx.timestamp);
+    schedule_clock_check(ctx, &game);
+
+    schedule_bot_move(ctx, &game);
 
     // Analyze the game and update scores
     game = analyze_game(game);
@@ -6,10 +6,18 @@
  * like creating a game, placing a stone, or managing user connections.
  */
 
+pub mod bot_reducers;
+pub mod clock_reducers;
 pub mod game_reducers;
 pub mod message_reducers;
+pub mod scoring_reducers;
+pub mod sgf_reducers;
 pub mod user_reducers;
 
+pub use bot_reducers::*;
+pub use clock_reducers::*;
 pub use game_reducers::*;
 pub use message_reducers::*;
+pub use scoring_reducers::*;
+pub use sgf_reducers::*;
 pub use user_reducers::*;
\ No newline at end of file
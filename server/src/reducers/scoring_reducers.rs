@@ -0,0 +1,174 @@
+use crate::models::game::game;
+use crate::models::Game;
+use crate::reducers::bot_reducers::{bot_color, schedule_bot_move};
+use crate::reducers::clock_reducers::schedule_clock_check;
+use crate::scoring::analyze_game;
+use go_engine::bot::accepts_dead_stones;
+use go_engine::models::{GameResult, Occupant, Point};
+use go_engine::scoring::{remove_marked_stones, toggle_dead_group};
+use spacetimedb::{reducer, ReducerContext};
+
+/// Looks up a game in the scoring phase and the color of the player sending the request.
+///
+/// # Returns
+/// * `Ok((Game, Occupant))` - The game and the sender's color
+/// * `Err(String)` - If the game doesn't exist, isn't being scored, or the sender isn't
+///   one of its players
+fn find_scoring_game(ctx: &ReducerContext, game_id: u64) -> Result<(Game, Occupant), String> {
+    let game = match ctx.db.game().id().find(game_id) {
+        Some(g) => g,
+        None => return Err(format!("Game with id {} not found", game_id)),
+    };
+
+    if !game.scoring_phase {
+        return Err("The game is not being scored".to_string());
+    }
+
+    let Some(color) = game.player_color(ctx.sender) else {
+        return Err("You are not a player in this game".to_string());
+    };
+    Ok((game, color))
+}
+
+/// Marks a group of stones dead, or alive again if it is already marked dead.
+///
+/// Either player may toggle any group. The score is recalculated straight away, and
+/// any earlier acceptance of the score by either player is withdrawn.
+///
+/// # Arguments
+/// * `ctx` - The reducer context containing sender identity
+/// * `game_id` - The ID of the game
+/// * `x` - The x-coordinate (column) of a stone in the group
+/// * `y` - The y-coordinate (row) of a stone in the group
+///
+/// # Returns
+/// * `Ok(())` - The group was toggled
+/// * `Err(String)` - Error message if the group could not be toggled
+#[reducer]
+pub fn toggle_dead_stones(ctx: &ReducerContext, game_id: u64, x: u8, y: u8) -> Result<(), String> {
+    let (mut game, _) = find_scoring_game(ctx, game_id)?;
+
+    let board = game
+        .as_board()
+        .map_err(|_| "Failed to parse game board".to_string())?;
+    let point = Point::on_board(y as usize, x as usize, game.board_size)?;
+    let dead_stones = toggle_dead_group(&board, &game.dead_points(), point)?;
+    game.set_dead_points(&dead_stones);
+
+    // Show both players the score with the new set of dead stones
+    game = analyze_game(game);
+    ctx.db.game().id().update(game);
+    Ok(())
+}
+
+/// Accepts the score shown in the scoring phase.
+///
+/// Once both players have accepted, the dead stones are taken off the board as
+/// prisoners and the game ends with the counted result (see [`record_acceptance`]).
+///
+/// # Arguments
+/// * `ctx` - The reducer context containing sender identity
+/// * `game_id` - The ID of the game
+///
+/// # Returns
+/// * `Ok(())` - The acceptance was recorded
+/// * `Err(String)` - Error message if the score could not be accepted
+#[reducer]
+pub fn accept_score(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
+    let (game, color) = find_scoring_game(ctx, game_id)?;
+    let bot = bot_color(ctx, &game);
+    let game = record_acceptance(game, color, bot)?;
+    ctx.db.game().id().update(game);
+    Ok(())
+}
+
+/// Records that a player accepts the score shown in the scoring phase, and ends the
+/// game with the counted result once both players have accepted.
+///
+/// A player holding both seats accepts for both colors. The built-in bot accepts
+/// along with its opponent only if the stones marked dead are the ones it finds dead
+/// itself (see [`accepts_dead_stones`]); otherwise it withholds its acceptance until
+/// the marks are changed to match or play resumes.
+///
+/// # Arguments
+/// * `game` - The game being scored
+/// * `color` - The color of the player accepting
+/// * `bot` - The color the built-in bot plays in the game, if any
+///
+/// # Returns
+/// * `Ok(Game)` - The game with the acceptance recorded, over if both players accepted
+/// * `Err(String)` - Error message if the board could not be read or written
+pub fn record_acceptance(
+    mut game: Game,
+    color: Occupant,
+    bot: Option<Occupant>,
+) -> Result<Game, String> {
+    let mut board = game
+        .as_board()
+        .map_err(|_| "Failed to parse game board".to_string())?;
+
+    let other = if Some(game.player_black) == game.player_white {
+        Some(if color == Occupant::Black { Occupant::White } else { Occupant::Black })
+    } else {
+        bot.filter(|_| accepts_dead_stones(&board, &game.dead_points()))
+    };
+    for player in [Some(color), other].into_iter().flatten() {
+        if player == Occupant::Black {
+            game.accepted_black = true;
+        } else {
+            game.accepted_white = true;
+        }
+    }
+
+    if game.accepted_black && game.accepted_white {
+        // Take the dead stones off the board and count the final position
+        let (black_prisoners, white_prisoners) =
+            remove_marked_stones(&mut board, &game.dead_points());
+        game.captures_black += black_prisoners;
+        game.captures_white += white_prisoners;
+        game.board = serde_json::to_string(&board.spots)
+            .map_err(|e| format!("Failed to serialize board: {}", e))?;
+        game.dead_stones.clear();
+        game.game_over = true;
+
+        game = analyze_game(game);
+        let scores = (game.final_score_black, game.final_score_white);
+        if let (Some(black_score), Some(white_score)) = scores {
+            game.finish(&GameResult::from_score(black_score, white_score));
+        }
+        log::info!("Game {} ended: {}", game.id, game.result.as_deref().unwrap_or("?"));
+    }
+    Ok(game)
+}
+
+/// Leaves the scoring phase and resumes play.
+///
+/// Either player may resume play, for example to settle a group whose status the
+/// players disagree on. The marked dead stones are cleared and the game continues with
+/// the player whose turn it was after the second pass.
+///
+/// # Arguments
+/// * `ctx` - The reducer context containing sender identity
+/// * `game_id` - The ID of the game
+///
+/// # Returns
+/// * `Ok(())` - Play was resumed
+/// * `Err(String)` - Error message if play could not be resumed
+#[reducer]
+pub fn resume_play(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
+    let (mut game, _) = find_scoring_game(ctx, game_id)?;
+
+    game.scoring_phase = false;
+    game.set_dead_points(&[]);
+    game.passes = 0;
+
+    // Restart the clock of the player to move
+    game.turn_started_at = Some(ctx.timestamp);
+    schedule_clock_check(ctx, &game);
+    schedule_bot_move(ctx, &game);
+
+    game = analyze_game(game);
+    ctx.db.game().id().update(game);
+    log::info!("Play resumed in game {}", game_id);
+    Ok(())
+}
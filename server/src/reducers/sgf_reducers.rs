@@ -0,0 +1,300 @@
+use crate::models::game::{
+    game, game_comment, game_markup, game_move, game_sgf, game_variation, user,
+};
+use crate::models::{Game, GameComment, GameMarkup, GameMove, GameSgf, GameVariation};
+use crate::scoring::analyze_game;
+use go_engine::models::{position_key, validate_board_size, validate_komi, Occupant, SpotState};
+use go_engine::sgf::{read_sgf, write_sgf, SgfGame, SgfSetup};
+use go_engine::utils::{place_setup_stone, position_hash, remove_setup_stone};
+use spacetimedb::{reducer, Identity, ReducerContext, Table, Timestamp};
+
+/// Returns the display name of a user, if they have set one.
+fn player_name(ctx: &ReducerContext, identity: Identity) -> Option<String> {
+    ctx.db.user().identity().find(identity).and_then(|user| user.name)
+}
+
+/// Exports a game in SGF format.
+///
+/// Any finished or ongoing game can be exported. The SGF text is written to the
+/// public `game_sgf` table, replacing any earlier export of the same game, where
+/// the client can read it. Comments, markup and variations of an imported game are
+/// written along with its moves.
+///
+/// # Arguments
+/// * `ctx` - The reducer context
+/// * `game_id` - The ID of the game to export
+///
+/// # Returns
+/// * `Ok(())` - The game was exported
+/// * `Err(String)` - Error message if the game does not exist
+#[reducer]
+pub fn export_sgf(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
+    let game = match ctx.db.game().id().find(game_id) {
+        Some(g) => g,
+        None => return Err(format!("Game with id {} not found", game_id)),
+    };
+
+    let mut moves: Vec<_> = ctx.db.game_move().game_id().filter(game_id).collect();
+    moves.sort_by_key(|mv| mv.id);
+
+    let mut record = game.sgf_record(
+        &moves,
+        player_name(ctx, game.player_black),
+        game.player_white.and_then(|white| player_name(ctx, white)),
+    );
+    let mut comments: Vec<_> = ctx.db.game_comment().game_id().filter(game_id).collect();
+    comments.sort_by_key(|comment| comment.id);
+    record.comments = comments.iter().map(GameComment::to_sgf).collect();
+    let mut markup: Vec<_> = ctx.db.game_markup().game_id().filter(game_id).collect();
+    markup.sort_by_key(|mark| mark.id);
+    record.markup = markup
+        .iter()
+        .filter_map(|mark| mark.to_sgf(game.board_size))
+        .collect();
+    let mut variations: Vec<_> = ctx.db.game_variation().game_id().filter(game_id).collect();
+    variations.sort_by_key(|variation| variation.id);
+    record.variations = variations.iter().map(GameVariation::to_sgf).collect();
+    let export = GameSgf {
+        game_id,
+        sgf: write_sgf(&record),
+        exported_at: ctx.timestamp,
+    };
+    if ctx.db.game_sgf().game_id().find(game_id).is_some() {
+        ctx.db.game_sgf().game_id().update(export);
+    } else {
+        ctx.db.game_sgf().insert(export);
+    }
+    Ok(())
+}
+
+/// Creates a game from an SGF file, for reviewing a game played elsewhere.
+///
+/// The file's main line is replayed move by move under the game's ruleset, so a
+/// record with an illegal move is rejected, naming the move. Setup stones in the
+/// root node give the starting position; Black's count as handicap stones when the
+/// file declares a handicap. Stones added or removed later in the main line are
+/// applied between the moves. The sender plays both colors, so the game can be
+/// continued from where the record ends. A record with a result is imported as a
+/// finished game. Comments, markup and variations are stored in the `game_comment`,
+/// `game_markup` and `game_variation` tables. Player names and time settings are
+/// not imported, and imported games are untimed.
+///
+/// # Arguments
+/// * `ctx` - The reducer context containing sender identity and timestamp
+/// * `sgf` - The contents of the SGF file
+///
+/// # Returns
+/// * `Ok(())` - The game was created
+/// * `Err(String)` - Error message if the file is invalid or contains an illegal move
+#[reducer]
+pub fn import_sgf(ctx: &ReducerContext, sgf: String) -> Result<(), String> {
+    let record = read_sgf(&sgf)?;
+    let game_id: u64 = ctx.timestamp.to_micros_since_unix_epoch() as u64;
+    let (game, moves) = game_from_sgf(&record, game_id, ctx.sender, ctx.timestamp)?;
+
+    for entry in moves {
+        ctx.db.game_move().insert(entry);
+    }
+    for comment in &record.comments {
+        ctx.db.game_comment().insert(GameComment::from_sgf(game_id, comment));
+    }
+    for mark in &record.markup {
+        ctx.db.game_markup().insert(GameMarkup::from_sgf(game_id, game.board_size, mark));
+    }
+    for variation in &record.variations {
+        ctx.db.game_variation().insert(GameVariation::from_sgf(game_id, variation));
+    }
+    log::info!(
+        "Imported game {} from SGF ({} moves, {} rules)",
+        game_id,
+        game.move_count,
+        game.ruleset
+    );
+    ctx.db.game().insert(game);
+    Ok(())
+}
+
+/// Builds a game and its move record from an SGF game record, replaying every move.
+///
+/// # Arguments
+/// * `record` - The game record read from the SGF file
+/// * `game_id` - The ID of the new game
+/// * `player` - The player who holds both seats
+/// * `now` - When the game is imported, used for moves without a timestamp
+///
+/// # Returns
+/// * `Ok((Game, Vec<GameMove>))` - The game and the entries for the `game_move` table
+/// * `Err(String)` - Error message if the record is invalid or contains an illegal move
+pub fn game_from_sgf(
+    record: &SgfGame,
+    game_id: u64,
+    player: Identity,
+    now: Timestamp,
+) -> Result<(Game, Vec<GameMove>), String> {
+    let size = record.board_size;
+    validate_board_size(size)?;
+    validate_komi(record.komi, size)?;
+
+    // Set up the starting position
+    let mut board: Vec<SpotState> = (0..(size as usize * size as usize))
+        .map(|_| SpotState {
+            occupant: Occupant::Empty,
+            move_number: None,
+            marker: None,
+            scoring_owner: None,
+            scoring_explanation: None,
+            group_status: None,
+            playable: true,
+        })
+        .collect();
+    for &point in &record.handicap_stones {
+        board = place_setup_stone(board, size, Occupant::Black, point)?;
+    }
+    for &point in &record.setup_white {
+        board = place_setup_stone(board, size, Occupant::White, point)?;
+    }
+
+    // Black moves first unless the record says otherwise, or the game has a handicap
+    let first_player = record
+        .first_player
+        .clone()
+        .or_else(|| record.moves.first().map(|mv| mv.color.clone()))
+        .unwrap_or(if record.handicap > 0 { Occupant::White } else { Occupant::Black });
+
+    let mut game = Game {
+        id: game_id,
+        player_black: player,
+        player_white: Some(player), // The importer reviews the game as both players
+        board: serde_json::to_string(&board).unwrap(),
+        turn: if first_player == Occupant::White { "W" } else { "B" }.to_string(),
+        passes: 0,
+        board_size: size,
+        position_history: vec![position_key(position_hash(&board, size), &first_player)],
+        ruleset: record.ruleset.as_str().to_string(),
+        handicap: record.handicap,
+        handicap_pending: 0,
+        komi: record.komi,
+        captures_black: 0,
+        captures_white: 0,
+        move_count: 0,
+        game_over: false,
+        scoring_phase: false,
+        dead_stones: Vec::new(),
+        accepted_black: false,
+        accepted_white: false,
+        final_score_black: None,
+        final_score_white: None,
+        winner: None,
+        result_reason: None,
+        result_margin: None,
+        result: None,
+        time_control: None,
+        clock_black: None,
+        clock_white: None,
+        turn_started_at: None,
+        bot_level: None,
+    };
+
+    // Record the setup stones; Black's are the handicap if the file declares one
+    let mut moves = Vec::new();
+    let black_action = if record.handicap > 0 { "handicap" } else { "setup" };
+    for &point in &record.handicap_stones {
+        moves.push(game.record_move(&Occupant::Black, black_action, Some(point), 0, now));
+    }
+    for &point in &record.setup_white {
+        moves.push(game.record_move(&Occupant::White, "setup", Some(point), 0, now));
+    }
+
+    // Replay the moves, rejecting the record at the first illegal one, and set up
+    // stones between them
+    let mut setup = record.setup.iter().peekable();
+    for (i, mv) in record.moves.iter().enumerate() {
+        while let Some(step) = setup.next_if(|step| step.before_move <= i) {
+            moves.extend(apply_setup(&mut game, step, now)?);
+        }
+        let played_at = mv
+            .played_at
+            .as_deref()
+            .and_then(|timestamp| Timestamp::parse_from_rfc3339(timestamp).ok())
+            .unwrap_or(now);
+
+        match mv.point {
+            Some(point) => {
+                let move_number = game.move_count + 1;
+                let entry = game
+                    .play_stone(&mv.color, point, played_at)
+                    .map_err(|e| format!("Move {} is illegal: {}", move_number, e))?;
+                moves.push(entry);
+            }
+            None => {
+                moves.push(game.record_move(&mv.color, "pass", None, 0, played_at));
+                game.passes += 1;
+                let next = if mv.color == Occupant::Black { Occupant::White } else { Occupant::Black };
+                game.turn = if next == Occupant::White { "W" } else { "B" }.to_string();
+                let board = game
+                    .as_board()
+                    .map_err(|_| "Failed to parse game board".to_string())?;
+                game.position_history.push(position_key(board.position_hash(), &next));
+            }
+        }
+    }
+
+    for step in setup {
+        moves.extend(apply_setup(&mut game, step, now)?);
+    }
+
+    // Analyze the game and update scores, then apply the recorded result
+    game = analyze_game(game);
+    if let Some(result) = &record.result {
+        game.finish(result);
+    }
+    Ok((game, moves))
+}
+
+/// Adds and removes the stones of a setup step between moves of an imported game.
+///
+/// Points are cleared first, then stones added; an added stone replaces whatever
+/// was on its point, as SGF specifies. The resulting position is added to the
+/// game's history, with the same player to move.
+///
+/// # Arguments
+/// * `game` - The game being imported
+/// * `setup` - The setup step
+/// * `now` - When the game is imported
+///
+/// # Returns
+/// * `Ok(Vec<GameMove>)` - Entries for the stones added and removed
+/// * `Err(String)` - Error message if a stone is left without liberties
+fn apply_setup(
+    game: &mut Game,
+    setup: &SgfSetup,
+    now: Timestamp,
+) -> Result<Vec<GameMove>, String> {
+    let size = game.board_size;
+    let mut board: Vec<SpotState> = serde_json::from_str(&game.board)
+        .map_err(|_| "Failed to parse game board".to_string())?;
+    let before_move = game.move_count + 1;
+    let invalid = |e: String| format!("Setup before move {} is invalid: {}", before_move, e);
+
+    // Record every stone taken off, including those replaced by an added stone, so
+    // the move record replays to the same position
+    let mut entries = Vec::new();
+    for &point in setup.empty.iter().chain(&setup.black).chain(&setup.white) {
+        let removed = board[point.index(size)].occupant.clone();
+        board = remove_setup_stone(board, size, point).map_err(invalid)?;
+        if removed != Occupant::Empty {
+            entries.push(game.record_move(&removed, "clear", Some(point), 0, now));
+        }
+    }
+    for (color, points) in [(Occupant::Black, &setup.black), (Occupant::White, &setup.white)] {
+        for &point in points {
+            board = place_setup_stone(board, size, color.clone(), point).map_err(invalid)?;
+            entries.push(game.record_move(&color, "setup", Some(point), 0, now));
+        }
+    }
+
+    let to_move = if game.turn == "W" { Occupant::White } else { Occupant::Black };
+    game.position_history.push(position_key(position_hash(&board, size), &to_move));
+    game.board = serde_json::to_string(&board).unwrap();
+    Ok(entries)
+}
@@ -1,11 +1,16 @@
-use crate::models::{Board, Game, Occupant, ScoringMethod};
-use super::territory::determine_territory;
+use crate::models::Game;
+use go_engine::models::Occupant;
+use go_engine::scoring::{calculate_score, remove_marked_stones};
 
 /// Performs a comprehensive scoring analysis on a game.
 ///
-/// This function takes a Game object, deserializes its board data, runs territory and
-/// playability analysis, calculates the current score, and then updates the game object
-/// with the results.
+/// This function takes a Game object, deserializes its board data, runs territory,
+/// group status and playability analysis, calculates the current score according to the game's ruleset,
+/// and then updates the game object with the results.
+///
+/// Stones marked dead during the scoring phase stay on the board, marked "dead", but
+/// are scored as if they had been taken off: their spots count as territory for the
+/// other player and they are added to the prisoners.
 ///
 /// # Arguments
 /// * `game` - The Game object to analyze
@@ -17,8 +22,32 @@ pub fn analyze_game(mut game: Game) -> Game {
     let board_result = game.as_board();
     
     if let Ok(mut board_obj) = board_result {
-        // Run in-place scoring annotation
-        board_obj.annotate_for_scoring();
+        let rules = game.rules();
+
+        // Mark the stones currently agreed to be dead
+        let dead_stones = game.dead_points();
+        for spot in board_obj.spots.iter_mut() {
+            if spot.occupant != Occupant::Empty && spot.marker.as_deref() == Some("dead") {
+                spot.marker = None;
+            }
+        }
+        for point in &dead_stones {
+            if let Some(spot) = board_obj.get_mut(*point) {
+                spot.marker = Some("dead".to_string());
+            }
+        }
+
+        // Score the board as it would be with the dead stones taken off
+        let mut scoring_board = board_obj.clone();
+        let (dead_white, dead_black) = remove_marked_stones(&mut scoring_board, &dead_stones);
+        scoring_board.annotate_for_scoring(rules.scoring_method());
+        for (spot, scored) in board_obj.spots.iter_mut().zip(&scoring_board.spots) {
+            spot.scoring_owner = scored.scoring_owner.clone();
+            spot.scoring_explanation = scored.scoring_explanation.clone();
+        }
+
+        // Mark the stones that are unconditionally alive or dead
+        board_obj.annotate_group_status();
         
         // Determine which spots are legal moves based on whose turn is next
         let current_turn = match game.turn.as_str() {
@@ -26,10 +55,14 @@ pub fn analyze_game(mut game: Game) -> Game {
             "W" => Occupant::White,
             _ => panic!("Invalid turn value: {}", game.turn),
         };
-        board_obj.annotate_playability(current_turn);
+        board_obj.annotate_playability(current_turn, rules, &game.position_history);
         
-        // Calculate current score using Chinese rules (area scoring) with standard 6.5 komi
-        let (black_score, white_score) = calculate_score(&board_obj, ScoringMethod::Area, 6.5);
+        // Calculate current score using the game's ruleset and komi, including any
+        // compensation White receives for Black's handicap stones
+        let komi = game.komi + rules.handicap_compensation(game.handicap);
+        let captures = (game.captures_black + dead_white, game.captures_white + dead_black);
+        let (black_score, white_score) =
+            calculate_score(&scoring_board, rules.scoring_method(), komi, captures);
         game.final_score_black = Some(black_score);
         game.final_score_white = Some(white_score);
         
@@ -42,48 +75,3 @@ pub fn analyze_game(mut game: Game) -> Game {
     
     game
 }
-
-/// Calculates the score for both players using the specified scoring method.
-///
-/// Go has two main scoring systems:
-/// - Area scoring (Chinese rules): score = stones on board + surrounded territory
-/// - Territory scoring (Japanese rules): score = surrounded territory only
-///
-/// # Arguments
-/// * `board` - The game board to analyze
-/// * `method` - Which scoring method to use (Area or Territory)
-/// * `komi` - Compensation points given to White (typically 6.5 to prevent draws)
-///
-/// # Returns
-/// A tuple (black_score, white_score) with the final scores
-pub fn calculate_score(board: &Board, method: ScoringMethod, komi: f32) -> (f32, f32) {
-    // Count stones on board
-    let mut black_stones = 0;
-    let mut white_stones = 0;
-    
-    for spot in &board.spots {
-        match spot.occupant {
-            Occupant::Black => black_stones += 1,
-            Occupant::White => white_stones += 1,
-            Occupant::Empty => {} // Skip empty spots
-        }
-    }
-    
-    // Calculate territory (empty intersections surrounded by a single color)
-    let (black_territory, white_territory) = determine_territory(board);
-    
-    match method {
-        ScoringMethod::Area => {
-            // Chinese rules: stones + territory
-            let black_score = black_stones as f32 + black_territory as f32;
-            let white_score = white_stones as f32 + white_territory as f32 + komi;
-            (black_score, white_score)
-        }
-        ScoringMethod::Territory => {
-            // Japanese rules: territory only (plus captures, which aren't tracked here)
-            let black_score = black_territory as f32;
-            let white_score = white_territory as f32 + komi;
-            (black_score, white_score)
-        }
-    }
-}
\ No newline at end of file
@@ -1,17 +1,10 @@
 /*!
- * Scoring and board analysis for the Go game.
+ * Game analysis for the Go game.
  *
- * This module contains functionality for analyzing the game board, including:
- * - Group detection and liberty counting
- * - Territory determination 
- * - Scoring calculations (both area and territory scoring methods)
- * - Dead stone removal
+ * This module applies the `go-engine` scoring and playability analysis to
+ * stored games, so that clients receive an annotated board and live score.
  */
 
 pub mod analysis;
-pub mod groups;
-pub mod territory;
 
 pub use analysis::*;
-pub use groups::*;
-pub use territory::*;
\ No newline at end of file
@@ -1,5 +1,7 @@
-use crate::models::game::game;
-use crate::models::{Game, Occupant, SpotState};
+use crate::models::game::{game, game_move};
+use crate::models::Game;
+use go_engine::models::{position_key, Occupant, Point, Ruleset, SpotState};
+use go_engine::utils::{fixed_handicap_points, position_hash};
 use serde_json;
 use spacetimedb::{ReducerContext, Table};
 
@@ -17,6 +19,7 @@ pub fn seed_sample_games(ctx: &ReducerContext) {
             marker: None,
             scoring_owner: None,
             scoring_explanation: None,
+            group_status: None,
             playable: true,
         };
         board_size_usize * board_size_usize
@@ -24,17 +27,29 @@ pub fn seed_sample_games(ctx: &ReducerContext) {
 
     // Place some sample stones
     // Create a simple configuration with a black group and a white group
-    let black_positions = vec![(3, 3), (3, 4), (4, 3), (4, 4), (2, 3)];
-    let white_positions = vec![(1, 3), (2, 2), (3, 2), (4, 2), (5, 3)];
+    let black_positions = [
+        Point::new(3, 3),
+        Point::new(4, 3),
+        Point::new(3, 4),
+        Point::new(4, 4),
+        Point::new(3, 2),
+    ];
+    let white_positions = [
+        Point::new(3, 1),
+        Point::new(2, 2),
+        Point::new(2, 3),
+        Point::new(2, 4),
+        Point::new(3, 5),
+    ];
 
-    for (idx, &(x, y)) in black_positions.iter().enumerate() {
-        let index = y * board_size_usize + x;
+    for (idx, point) in black_positions.iter().enumerate() {
+        let index = point.index(board_size);
         board[index].occupant = Occupant::Black;
         board[index].move_number = Some(idx as u64 * 2 + 1); // Odd move numbers for black
     }
 
-    for (idx, &(x, y)) in white_positions.iter().enumerate() {
-        let index = y * board_size_usize + x;
+    for (idx, point) in white_positions.iter().enumerate() {
+        let index = point.index(board_size);
         board[index].occupant = Occupant::White;
         board[index].move_number = Some(idx as u64 * 2 + 2); // Even move numbers for white
     }
@@ -43,7 +58,7 @@ pub fn seed_sample_games(ctx: &ReducerContext) {
 
     // Add the sample game to the database
     let game_id = ctx.timestamp.to_micros_since_unix_epoch() as u64;
-    ctx.db.game().insert(Game {
+    let mut game = Game {
         id: game_id,
         player_black: ctx.sender,
         player_white: Some(ctx.sender), // Same player as both to keep it simple
@@ -51,11 +66,36 @@ pub fn seed_sample_games(ctx: &ReducerContext) {
         turn: "B".to_string(),
         passes: 0,
         board_size,
-        previous_board: None,
+        position_history: vec![position_key(
+            position_hash(&board, board_size),
+            &Occupant::Black,
+        )],
+        ruleset: Ruleset::default().as_str().to_string(),
+        handicap: 0,
+        handicap_pending: 0,
+        komi: Ruleset::default().default_komi(0, board_size),
+        captures_black: 0,
+        captures_white: 0,
+        move_count: 0,
         game_over: false,
+        scoring_phase: false,
+        dead_stones: Vec::new(),
+        accepted_black: false,
+        accepted_white: false,
         final_score_black: None,
         final_score_white: None,
-    });
+        winner: None,
+        result_reason: None,
+        result_margin: None,
+        result: None,
+        time_control: None,
+        clock_black: None,
+        clock_white: None,
+        turn_started_at: None,
+        bot_level: None,
+    };
+    record_sample_moves(ctx, &mut game, &black_positions, &white_positions);
+    ctx.db.game().insert(game);
 
     // Create another sample game with a different configuration
     let board_size: u8 = 13;
@@ -69,23 +109,40 @@ pub fn seed_sample_games(ctx: &ReducerContext) {
             marker: None,
             scoring_owner: None,
             scoring_explanation: None,
+            group_status: None,
             playable: true,
         };
         board_size_usize * board_size_usize
     ];
 
     // Place some sample stones in a more complex pattern
-    let black_positions2 = vec![(6, 6), (7, 6), (5, 7), (6, 7), (7, 7), (8, 7), (6, 8)];
-    let white_positions2 = vec![(4, 6), (5, 6), (4, 7), (4, 8), (5, 8), (7, 8), (6, 9)];
+    let black_positions2 = [
+        Point::new(6, 6),
+        Point::new(6, 7),
+        Point::new(7, 5),
+        Point::new(7, 6),
+        Point::new(7, 7),
+        Point::new(7, 8),
+        Point::new(8, 6),
+    ];
+    let white_positions2 = [
+        Point::new(6, 4),
+        Point::new(6, 5),
+        Point::new(7, 4),
+        Point::new(8, 4),
+        Point::new(8, 5),
+        Point::new(8, 7),
+        Point::new(9, 6),
+    ];
 
-    for (idx, &(x, y)) in black_positions2.iter().enumerate() {
-        let index = y * board_size_usize + x;
+    for (idx, point) in black_positions2.iter().enumerate() {
+        let index = point.index(board_size);
         board2[index].occupant = Occupant::Black;
         board2[index].move_number = Some(idx as u64 * 2 + 1);
     }
 
-    for (idx, &(x, y)) in white_positions2.iter().enumerate() {
-        let index = y * board_size_usize + x;
+    for (idx, point) in white_positions2.iter().enumerate() {
+        let index = point.index(board_size);
         board2[index].occupant = Occupant::White;
         board2[index].move_number = Some(idx as u64 * 2 + 2);
     }
@@ -94,7 +151,7 @@ pub fn seed_sample_games(ctx: &ReducerContext) {
 
     // Add the second sample game
     let game_id2 = ctx.timestamp.to_micros_since_unix_epoch() as u64 + 1;
-    ctx.db.game().insert(Game {
+    let mut game2 = Game {
         id: game_id2,
         player_black: ctx.sender,
         player_white: Some(ctx.sender),
@@ -102,9 +159,116 @@ pub fn seed_sample_games(ctx: &ReducerContext) {
         turn: "W".to_string(),
         passes: 0,
         board_size,
-        previous_board: None,
+        position_history: vec![position_key(
+            position_hash(&board2, board_size),
+            &Occupant::White,
+        )],
+        ruleset: Ruleset::default().as_str().to_string(),
+        handicap: 0,
+        handicap_pending: 0,
+        komi: Ruleset::default().default_komi(0, board_size),
+        captures_black: 0,
+        captures_white: 0,
+        move_count: 0,
         game_over: false,
+        scoring_phase: false,
+        dead_stones: Vec::new(),
+        accepted_black: false,
+        accepted_white: false,
         final_score_black: None,
         final_score_white: None,
-    });
+        winner: None,
+        result_reason: None,
+        result_margin: None,
+        result: None,
+        time_control: None,
+        clock_black: None,
+        clock_white: None,
+        turn_started_at: None,
+        bot_level: None,
+    };
+    record_sample_moves(ctx, &mut game2, &black_positions2, &white_positions2);
+    ctx.db.game().insert(game2);
+
+    // Create a third sample game: a 19x19 board with a four-stone handicap
+    let board_size: u8 = 19;
+    let board_size_usize = board_size as usize;
+    let handicap: u8 = 4;
+
+    let mut board3 = vec![
+        SpotState {
+            occupant: Occupant::Empty,
+            move_number: None,
+            marker: None,
+            scoring_owner: None,
+            scoring_explanation: None,
+            group_status: None,
+            playable: true,
+        };
+        board_size_usize * board_size_usize
+    ];
+
+    let handicap_positions = fixed_handicap_points(board_size, handicap).unwrap();
+    for point in &handicap_positions {
+        let index = point.index(board_size);
+        board3[index].occupant = Occupant::Black;
+        board3[index].move_number = Some(0); // Handicap stones precede the first move
+    }
+
+    let board3_json = serde_json::to_string(&board3).unwrap();
+
+    // Add the third sample game, with White to make the first move
+    let game_id3 = ctx.timestamp.to_micros_since_unix_epoch() as u64 + 2;
+    let mut game3 = Game {
+        id: game_id3,
+        player_black: ctx.sender,
+        player_white: Some(ctx.sender),
+        board: board3_json,
+        turn: "W".to_string(),
+        passes: 0,
+        board_size,
+        position_history: vec![position_key(
+            position_hash(&board3, board_size),
+            &Occupant::White,
+        )],
+        ruleset: Ruleset::default().as_str().to_string(),
+        handicap,
+        handicap_pending: 0,
+        komi: Ruleset::default().default_komi(handicap, board_size),
+        captures_black: 0,
+        captures_white: 0,
+        move_count: 0,
+        game_over: false,
+        scoring_phase: false,
+        dead_stones: Vec::new(),
+        accepted_black: false,
+        accepted_white: false,
+        final_score_black: None,
+        final_score_white: None,
+        winner: None,
+        result_reason: None,
+        result_margin: None,
+        result: None,
+        time_control: None,
+        clock_black: None,
+        clock_white: None,
+        turn_started_at: None,
+        bot_level: None,
+    };
+    for point in handicap_positions {
+        let entry = game3.record_move(&Occupant::Black, "handicap", Some(point), 0, ctx.timestamp);
+        ctx.db.game_move().insert(entry);
+    }
+    ctx.db.game().insert(game3);
+}
+
+/// Records the sample stones as alternating moves, Black first, matching the move
+/// numbers written on the board.
+fn record_sample_moves(ctx: &ReducerContext, game: &mut Game, black: &[Point], white: &[Point]) {
+    for (&black_point, &white_point) in black.iter().zip(white) {
+        let entry = game.record_move(&Occupant::Black, "play", Some(black_point), 0, ctx.timestamp);
+        ctx.db.game_move().insert(entry);
+        let entry = game.record_move(&Occupant::White, "play", Some(white_point), 0, ctx.timestamp);
+        ctx.db.game_move().insert(entry);
+    }
 }
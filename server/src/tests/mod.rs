@@ -1,13 +1,9 @@
 /*!
- * Unit and integration tests for the Go game.
- * 
- * This module contains tests for game logic, scoring mechanisms,
- * and rule enforcement. It validates that the core gameplay functions
- * work correctly under various scenarios.
+ * Tests for the game logic of the SpacetimeDB module.
+ *
+ * The rules themselves are tested in the `go-engine` crate; these tests cover
+ * how the module builds and updates games, without a database.
  */
 
-pub mod game_tests;
 pub mod scoring_tests;
-pub mod test_utils;
-
-pub use test_utils::*;
+pub mod sgf_import_tests;
@@ -0,0 +1,214 @@
+use crate::models::{Game, GameComment, GameMarkup, GameVariation};
+use crate::reducers::game_from_sgf;
+use go_engine::models::{Occupant, Point, SpotState, MAX_BOARD_SIZE};
+use go_engine::sgf::{read_sgf, write_sgf, SgfSetup};
+use spacetimedb::{Identity, Timestamp};
+
+/// The player importing the games.
+fn importer() -> Identity {
+    Identity::from_byte_array([1; 32])
+}
+
+/// Returns what is on a point of a game's board.
+fn occupant(game: &Game, row: u8, col: u8) -> Occupant {
+    let board: Vec<SpotState> = serde_json::from_str(&game.board).unwrap();
+    board[Point::new(row, col).index(game.board_size)].occupant.clone()
+}
+
+/// Tests that the importer can continue an imported game with White's move.
+#[test]
+fn test_imported_game_continues_with_white() {
+    let record = read_sgf("(;GM[1]FF[4]SZ[9];B[ee];W[ce];B[gc])").unwrap();
+    let now = Timestamp::from_micros_since_unix_epoch(0);
+    let (mut game, moves) = game_from_sgf(&record, 1, importer(), now).unwrap();
+    assert_eq!(moves.len(), 3);
+    assert_eq!(game.turn, "W");
+
+    // The importer holds both seats and plays whichever color is to move
+    let color = game.player_color(importer());
+    assert_eq!(color, Some(Occupant::White));
+    let entry = game.play_stone(&Occupant::White, Point::new(6, 6), now).unwrap();
+    assert_eq!(entry.color, "W");
+    assert_eq!(entry.move_number, 4);
+    assert_eq!(game.turn, "B");
+    assert_eq!(game.player_color(importer()), Some(Occupant::Black));
+
+    // Anyone else is not a player
+    assert_eq!(game.player_color(Identity::from_byte_array([2; 32])), None);
+}
+
+/// Tests that an illegal move in the record is rejected, naming the move.
+#[test]
+fn test_import_rejects_illegal_move() {
+    let record = read_sgf("(;GM[1]FF[4]SZ[9];B[ee];W[ee])").unwrap();
+    let now = Timestamp::from_micros_since_unix_epoch(0);
+    match game_from_sgf(&record, 1, importer(), now) {
+        Err(error) => assert!(error.starts_with("Move 2 is illegal"), "{}", error),
+        Ok(_) => panic!("Imported a record with an illegal move"),
+    }
+}
+
+/// Tests that stones added and removed between moves are applied, recorded and
+/// exported again.
+#[test]
+fn test_import_setup_between_moves() {
+    let record =
+        read_sgf("(;SZ[9]AB[aa]AW[bb]AE[aa];B[ee];W[ce];AB[cc]AW[gg]AE[ee];B[dd])").unwrap();
+    let now = Timestamp::from_micros_since_unix_epoch(0);
+    let (game, moves) = game_from_sgf(&record, 1, importer(), now).unwrap();
+
+    // The root clears its own stone, and the later setup clears Black's first move
+    assert_eq!(occupant(&game, 0, 0), Occupant::Empty);
+    assert_eq!(occupant(&game, 1, 1), Occupant::White);
+    assert_eq!(occupant(&game, 4, 4), Occupant::Empty);
+    assert_eq!(occupant(&game, 2, 2), Occupant::Black);
+    assert_eq!(occupant(&game, 6, 6), Occupant::White);
+    assert_eq!(occupant(&game, 3, 3), Occupant::Black);
+    assert_eq!(game.move_count, 3);
+    assert_eq!(game.turn, "W");
+
+    // Each setup step adds a position to the history
+    assert_eq!(game.position_history.len(), 6);
+
+    let actions: Vec<_> = moves
+        .iter()
+        .map(|mv| (mv.action.as_str(), mv.color.as_str(), mv.point.as_deref()))
+        .collect();
+    assert_eq!(
+        actions,
+        [
+            ("setup", "B", Some("A9")),
+            ("setup", "W", Some("B8")),
+            ("clear", "B", Some("A9")),
+            ("play", "B", Some("E5")),
+            ("play", "W", Some("C5")),
+            ("clear", "B", Some("E5")),
+            ("setup", "B", Some("C7")),
+            ("setup", "W", Some("G3")),
+            ("play", "B", Some("D6")),
+        ]
+    );
+
+    let exported = game.sgf_record(&moves, None, None);
+    assert_eq!(exported.handicap_stones, record.handicap_stones);
+    assert_eq!(exported.setup_white, record.setup_white);
+    assert_eq!(exported.setup, record.setup);
+    assert_eq!(
+        exported.setup[1],
+        SgfSetup {
+            before_move: 2,
+            black: vec![Point::new(2, 2)],
+            white: vec![Point::new(6, 6)],
+            empty: vec![Point::new(4, 4)],
+        }
+    );
+}
+
+/// Tests that a setup stone replacing a stone of the other color records the stone
+/// taken off, and is exported as a single added stone.
+#[test]
+fn test_import_setup_replaces_stone() {
+    let record = read_sgf("(;SZ[9];B[ee];W[ce];AW[ee];B[dd])").unwrap();
+    let now = Timestamp::from_micros_since_unix_epoch(0);
+    let (game, moves) = game_from_sgf(&record, 1, importer(), now).unwrap();
+    assert_eq!(occupant(&game, 4, 4), Occupant::White);
+
+    let actions: Vec<_> = moves
+        .iter()
+        .map(|mv| (mv.action.as_str(), mv.color.as_str(), mv.point.as_deref()))
+        .collect();
+    assert_eq!(
+        actions,
+        [
+            ("play", "B", Some("E5")),
+            ("play", "W", Some("C5")),
+            ("clear", "B", Some("E5")),
+            ("setup", "W", Some("E5")),
+            ("play", "B", Some("D6")),
+        ]
+    );
+
+    let exported = game.sgf_record(&moves, None, None);
+    assert_eq!(exported.setup, record.setup);
+    assert!(exported.setup[0].empty.is_empty());
+}
+
+/// Tests that a setup stone left without liberties is rejected.
+#[test]
+fn test_import_rejects_setup_without_liberties() {
+    let record = read_sgf("(;SZ[9];B[ee];AB[ab][ba]AW[aa])").unwrap();
+    let now = Timestamp::from_micros_since_unix_epoch(0);
+    match game_from_sgf(&record, 1, importer(), now) {
+        Err(error) => assert!(error.starts_with("Setup before move 2 is invalid"), "{}", error),
+        Ok(_) => panic!("Imported a setup stone without liberties"),
+    }
+}
+
+/// Tests that a record with an unsupported board size is rejected.
+#[test]
+fn test_import_rejects_board_size() {
+    let mut record = read_sgf("(;SZ[9];B[ee])").unwrap();
+    let now = Timestamp::from_micros_since_unix_epoch(0);
+    for size in [0, MAX_BOARD_SIZE + 1] {
+        record.board_size = size;
+        match game_from_sgf(&record, 1, importer(), now) {
+            Err(error) => assert!(error.starts_with("Board size must be between"), "{}", error),
+            Ok(_) => panic!("Imported a game on a {}x{} board", size, size),
+        }
+    }
+}
+
+/// Tests that comments, markup and variations are stored for their tables and
+/// exported with the game again.
+#[test]
+fn test_import_keeps_comments_markup_and_variations() {
+    let record =
+        read_sgf("(;SZ[9]C[Opening]SQ[cc];B[ee]LB[ee:1](;W[ce]C[Solid])(;W[gg];B[gc]))")
+            .unwrap();
+    let now = Timestamp::from_micros_since_unix_epoch(0);
+    let (game, moves) = game_from_sgf(&record, 1, importer(), now).unwrap();
+
+    let comments: Vec<_> = record
+        .comments
+        .iter()
+        .map(|comment| GameComment::from_sgf(game.id, comment))
+        .collect();
+    let markup: Vec<_> = record
+        .markup
+        .iter()
+        .map(|mark| GameMarkup::from_sgf(game.id, game.board_size, mark))
+        .collect();
+    let variations: Vec<_> = record
+        .variations
+        .iter()
+        .map(|variation| GameVariation::from_sgf(game.id, variation))
+        .collect();
+    let entries: Vec<_> = comments
+        .iter()
+        .map(|comment| (comment.move_number, comment.text.as_str()))
+        .collect();
+    assert_eq!(entries, [(0, "Opening"), (2, "Solid")]);
+    let marks: Vec<_> = markup
+        .iter()
+        .map(|mark| {
+            let label = mark.label.as_deref();
+            (mark.move_number, mark.shape.as_str(), mark.point.as_str(), label)
+        })
+        .collect();
+    assert_eq!(marks, [(0, "SQ", "C7", None), (1, "LB", "E5", Some("1"))]);
+    assert_eq!(variations.len(), 1);
+    assert_eq!(variations[0].move_number, 1);
+    assert_eq!(variations[0].sgf, "(;W[gg];B[gc])");
+
+    let mut exported = game.sgf_record(&moves, None, None);
+    exported.comments = comments.iter().map(GameComment::to_sgf).collect();
+    exported.markup = markup
+        .iter()
+        .filter_map(|mark| mark.to_sgf(game.board_size))
+        .collect();
+    exported.variations = variations.iter().map(GameVariation::to_sgf).collect();
+    let again = read_sgf(&write_sgf(&exported)).unwrap();
+    assert_eq!(again.comments, record.comments);
+    assert_eq!(again.markup, record.markup);
+    assert_eq!(again.variations, record.variations);
+}